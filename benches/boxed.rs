@@ -0,0 +1,64 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use parsing::prelude::*;
+use parsing::str::*;
+
+type Request<'a> = ((&'a str, &'a str), &'a str);
+type Headers<'a> = Vec<(&'a str, &'a str)>;
+
+const METHODS: [&str; 9] = [
+    "GET", "POST", "PUT", "DELETE", "HEAD",
+    "CONNECT", "OPTIONS", "TRACE", "PATCH",
+];
+
+fn http_parser<'a>() -> impl ParseStr<'a, Output = (Request<'a>, Headers<'a>)> {
+    let method = OneOf::<String>::from(&METHODS);
+
+    let req = method.skip_right(one_char(' '))
+        .then(take_until(one_char(' ')))
+        .skip_right(one_char(' '))
+        .then(take_until(str_seq("\r\n")))
+        .skip_right(str_seq("\r\n"));
+
+    let header = take_until(one_char(':'))
+        .skip_right(one_char(':'))
+        .skip_right(whitespace())
+        .then(take_until(str_seq("\r\n")))
+        .skip_right(str_seq("\r\n"));
+
+    req.then(many1(header)).skip_right(str_seq("\r\n"))
+}
+
+// the same grammar with every step behind a box, and the methods picked at runtime
+fn http_parser_boxed<'a>() -> BoxedParser<'a, &'a str, (Request<'a>, Headers<'a>)> {
+    let method = choice(METHODS.iter().map(|m| str_seq(m).boxed()).collect::<Vec<_>>()).boxed();
+
+    let req = method.skip_right(one_char(' ')).boxed()
+        .then(take_until(one_char(' ')).boxed()).boxed()
+        .skip_right(one_char(' ')).boxed()
+        .then(take_until(str_seq("\r\n")).boxed()).boxed()
+        .skip_right(str_seq("\r\n")).boxed();
+
+    let header = take_until(one_char(':')).boxed()
+        .skip_right(one_char(':')).boxed()
+        .skip_right(whitespace()).boxed()
+        .then(take_until(str_seq("\r\n")).boxed()).boxed()
+        .skip_right(str_seq("\r\n")).boxed();
+
+    req.then(many1(header).boxed()).boxed().skip_right(str_seq("\r\n")).boxed()
+}
+
+fn bench_fn(c: &mut Criterion) {
+    let input = include_str!("large.txt").to_string();
+    let input = input.replace("\r\n", "\n").replace("\n", "\r\n");
+    let input = input.as_str();
+
+    let mut group = c.benchmark_group("dispatch");
+    let parser = http_parser();
+    group.bench_function("static", |b| b.iter(|| parser.iter(black_box(input)).count()));
+    let parser = http_parser_boxed();
+    group.bench_function("boxed", |b| b.iter(|| parser.iter(black_box(input)).count()));
+    group.finish();
+}
+
+criterion_group!(benches, bench_fn);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use parsing::prelude::*;
+use parsing::str::*;
+
+// every line of the large input, split the way the http parser does it
+fn count_lines<'a>(input: &'a str, p: &impl ParseStr<'a, Output = &'a str>) -> usize {
+    let mut cursor = input;
+    let mut n = 0;
+    while let Ok((_, xs)) = p.parse(cursor) {
+        match xs.get(2..) {
+            Some(xs) => cursor = xs,
+            None => break,
+        }
+        n += 1;
+    }
+    n
+}
+
+fn count_lines_find(input: &str) -> usize {
+    let mut cursor = input;
+    let mut n = 0;
+    while let Some(idx) = cursor.find("\r\n") {
+        cursor = &cursor[idx + 2..];
+        n += 1;
+    }
+    n
+}
+
+fn count_colons_find(input: &str) -> usize {
+    let mut cursor = input;
+    let mut n = 0;
+    while let Some(idx) = cursor.find(':') {
+        cursor = &cursor[idx + 1..];
+        n += 1;
+    }
+    n
+}
+
+fn bench_fn(c: &mut Criterion) {
+    let input = include_str!("large.txt").to_string();
+    let input = input.replace("\r\n", "\n").replace("\n", "\r\n");
+    let input = input.as_str();
+
+    let mut group = c.benchmark_group("crlf");
+    let crlf = take_until(str_seq("\r\n"));
+    group.bench_function("str::find", |b| b.iter(|| count_lines_find(black_box(input))));
+    group.bench_function("take_until", |b| b.iter(|| count_lines(black_box(input), &crlf)));
+    group.finish();
+
+    let mut group = c.benchmark_group("colon");
+    let colon = take_until(one_char(':'));
+    group.bench_function("str::find", |b| b.iter(|| count_colons_find(black_box(input))));
+    group.bench_function("take_until", |b| {
+        b.iter(|| {
+            let mut cursor = black_box(input);
+            while let Ok((_, xs)) = colon.parse(cursor) {
+                cursor = &xs[1..];
+            }
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("take_while");
+    let line = CharClass::new().chars("\r\n").negate();
+    let class = take_while_in(line.clone());
+    let closure = take_while(|ch| ch != '\r' && ch != '\n');
+    group.bench_function("closure", |b| b.iter(|| count_lines(black_box(input), &closure)));
+    group.bench_function("char class", |b| b.iter(|| count_lines(black_box(input), &class)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_fn);
+criterion_main!(benches);
@@ -37,7 +37,7 @@ fn bench_fn(c: &mut Criterion) {
 
     c.bench_function("http parse", |b| {
         b.iter(|| {
-            parser.parse(input);
+            let _ = parser.parse(input);
         })
     });
 }
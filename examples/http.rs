@@ -5,22 +5,17 @@ type Request<'a> = (&'a str, &'a str, &'a str);
 type Headers<'a> = Vec<(&'a str, &'a str)>;
 
 fn http_parser<'a>() -> impl ParseStr<'a, Output = (Request<'a>, Headers<'a>)> {
-    let method = str_seq("GET")
-        .or(str_seq("POST"))
-        .or(str_seq("PUT"))
-        .or(str_seq("DELETE"))
-        .or(str_seq("HEAD"))
-        .or(str_seq("CONNECT"))
-        .or(str_seq("OPTIONS"))
-        .or(str_seq("TRACE"))
-        .or(str_seq("PATCH"));
+    let method = alt((
+        str_seq("GET"), str_seq("POST"), str_seq("PUT"),
+        str_seq("DELETE"), str_seq("HEAD"), str_seq("CONNECT"),
+        str_seq("OPTIONS"), str_seq("TRACE"), str_seq("PATCH"),
+    ));
 
-    let req = method.skip_right(one_char(' '))
-        .then(take_until(one_char(' ')))
-        .skip_right(one_char(' '))
-        .then(take_until(str_seq("\r\n")))
-        .skip_right(str_seq("\r\n"))
-        .map(|((a, b), c)| (a, b, c));
+    let req = seq((
+        terminated(method, one_char(' ')),
+        terminated(take_until(one_char(' ')), one_char(' ')),
+        terminated(take_until(str_seq("\r\n")), str_seq("\r\n")),
+    ));
 
     let header = take_until(one_char(':'))
         .skip_right(one_char(':'))
@@ -39,6 +34,6 @@ fn main() {
         Connection: Keep-Alive\r\n\r\n";
 
     let parser = http_parser();
-    let res = parser.parse(&message).unwrap();
+    let res = parser.parse(message).unwrap();
     println!("{:?}", res);
 }
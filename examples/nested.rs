@@ -0,0 +1,40 @@
+use parsing::prelude::*;
+use parsing::str::*;
+
+#[derive(Debug)]
+enum Value {
+    Number(i64),
+    List(Vec<Value>),
+}
+
+fn value<'a>() -> Recursive<'a, &'a str, Value> {
+    recursive(|value| {
+        let digits = CharClass::digit().char('-');
+        let number = preceded(peek(digits.clone()), take_while_in(digits))
+            .map(|s| Value::Number(s.parse().unwrap_or(0)));
+
+        let list = preceded(one_char('['), cut(terminated(sep_by(value, one_char(',')), one_char(']'))))
+            .map(Value::List);
+
+        alt((list, number))
+    })
+}
+
+fn sum(v: &Value) -> i64 {
+    match v {
+        Value::Number(n) => *n,
+        Value::List(xs) => xs.iter().map(sum).sum(),
+    }
+}
+
+fn main() {
+    let input = "[1,[2,3],[[4],-5],[]]";
+
+    let (v, _) = value().parse(input).unwrap();
+    println!("{:?}", v);
+    println!("sum = {}", sum(&v));
+
+    let input = "[1,[2,3";
+    let err = value().parse(input).unwrap_err();
+    println!("{}", err.report(input));
+}
@@ -1,9 +0,0 @@
-pub mod cell;
-pub mod parser;
-pub mod stream;
-
-pub mod prelude {
-    pub use crate::cell::*;
-    pub use crate::parser::*;
-    pub use crate::stream::*;
-}
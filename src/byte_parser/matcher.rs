@@ -1,41 +1,358 @@
-use crate::combinator::{
-    And,
-    Many0,
-    Many1,
-    Map,
-    Or,
-    Skip,
-    SkipN,
-    State,
-    Left,
-    Right,
-    TakeUntil,
-    TakeWhile
-};
-use crate::matcher::{Any, Digit, Seq};
-use crate::byte_parser::ParseByte;
-
-impl<'a, P1, P2> ParseByte<'a> for And<P1, P2>
+use std::slice;
+
+use crate::combinator::TakeWhile;
+use crate::error::{ErrorKind, Expected, ParseError};
+use crate::matcher::{match_ascii, CharClass, Digit, Indexed, Letter, Mismatch, NoCase, One, OneOf, Seq, Token, Whitespace};
+use crate::parser::{default_scan, impl_partial, parse_partial, scan_partial, Input, Parse, ParseResult, Partial};
+
+impl<'a> Parse<&'a [u8]> for Digit {
+    type Output = &'a [u8];
+
+    fn parse(&self, input: &'a [u8]) -> ParseResult<Self::Output, &'a [u8]> {
+        match input.first() {
+            Some(b) if b.is_ascii_digit() => {
+                Ok((&input[0..1], &input[1..]))
+            },
+            Some(_) => Err(ParseError::invalid(input, Expected::label("digit"))),
+            None => Err(ParseError::eof(input, Expected::label("digit")).need(1)),
+        }
+    }
+}
+
+impl<'a> Parse<&'a [u8]> for Letter {
+    type Output = &'a [u8];
+
+    fn parse(&self, input: &'a [u8]) -> ParseResult<Self::Output, &'a [u8]> {
+        match input.first() {
+            Some(b) if b.is_ascii_alphabetic() => {
+                Ok((&input[0..1], &input[1..]))
+            },
+            Some(_) => Err(ParseError::invalid(input, Expected::label("letter"))),
+            None => Err(ParseError::eof(input, Expected::label("letter")).need(1)),
+        }
+    }
+}
+
+impl<'a, T> Parse<&'a [T]> for One<T>
 where
-    P1: ParseByte<'a>,
-    P2: ParseByte<'a>,
+    T: Token,
 {
-    type Output = (P1::Output, P2::Output);
+    type Output = &'a [T];
 
-    fn parse(&self, input: &'a [u8]) -> Result<(Self::Output, &'a [u8]), &'a [u8]> {
-        let (a, input) = self.p1.parse(input)?;
-        let (b, input) = self.p2.parse(input)?;
-        Ok(((a, b), input))
+    fn parse(&self, input: &'a [T]) -> ParseResult<Self::Output, &'a [T]> {
+        match input.first() {
+            Some(x) if *x == self.val => {
+                Ok((&input[0..1], &input[1..]))
+            },
+            Some(_) => Err(ParseError::invalid(input, self.expected.clone())),
+            None => Err(ParseError::eof(input, self.expected.clone()).need(1)),
+        }
+    }
+
+    #[inline]
+    fn scan(&self, input: &'a [T]) -> Result<usize, ParseError<&'a [T]>> {
+        match T::find(input, slice::from_ref(&self.val)) {
+            Some(idx) => Ok(idx),
+            None => Err(ParseError::eof(&input[input.len()..], self.expected.clone())),
+        }
     }
 }
 
-impl<'a, P> ParseByte<'a> for Many0<P>
+impl<'a, T> Parse<&'a [T]> for OneOf<T>
 where
-    P: ParseByte<'a>,
+    T: Token,
 {
-    type Output = Vec<P::Output>;
+    type Output = &'a [T];
+
+    fn parse(&self, input: &'a [T]) -> ParseResult<Self::Output, &'a [T]> {
+        let mut err = match input.first() {
+            Some(x) if self.xs.contains(x) => {
+                return Ok((&input[0..1], &input[1..]));
+            },
+            Some(_) => ParseError::new(ErrorKind::Invalid, input),
+            None => ParseError::new(ErrorKind::EOF, input).need(1),
+        };
+
+        for expected in &self.expected {
+            err = err.expect(expected.clone());
+        }
+        Err(err)
+    }
+
+    #[inline]
+    fn scan(&self, input: &'a [T]) -> Result<usize, ParseError<&'a [T]>> {
+        if let Some(idx) = T::find_any(input, &self.xs) {
+            return Ok(idx);
+        }
+
+        let mut err = ParseError::new(ErrorKind::EOF, &input[input.len()..]);
+        for expected in &self.expected {
+            err = err.expect(expected.clone());
+        }
+        Err(err)
+    }
+}
+
+impl<'a> Parse<&'a [u8]> for OneOf<String> {
+    type Output = &'a [u8];
+
+    fn parse(&self, input: &'a [u8]) -> ParseResult<Self::Output, &'a [u8]> {
+        let (len, _) = self.trie.longest(input, input, false, || self.expected())?;
+        Ok(input.split_at(len))
+    }
+}
+
+impl<'a> Parse<&'a [u8]> for Indexed<OneOf<String>> {
+    type Output = usize;
+
+    fn parse(&self, input: &'a [u8]) -> ParseResult<Self::Output, &'a [u8]> {
+        let (len, idx) = self.inner.trie.longest(input, input, false, || self.inner.expected())?;
+        Ok((idx, &input[len..]))
+    }
+}
+
+// bytes need not be UTF-8, so only ASCII case is ignored here
+impl<'a> Parse<&'a [u8]> for NoCase<Seq<u8>> {
+    type Output = &'a [u8];
+
+    fn parse(&self, input: &'a [u8]) -> ParseResult<Self::Output, &'a [u8]> {
+        match match_ascii(input, &self.inner.seq) {
+            Ok(len) => Ok(input.split_at(len)),
+            Err(Mismatch::Short(n)) => Err(ParseError::eof(input, self.inner.expected()).need(n)),
+            Err(Mismatch::Invalid) => Err(ParseError::invalid(input, self.inner.expected())),
+        }
+    }
+}
+
+impl<'a> Parse<&'a [u8]> for NoCase<OneOf<String>> {
+    type Output = &'a [u8];
+
+    fn parse(&self, input: &'a [u8]) -> ParseResult<Self::Output, &'a [u8]> {
+        let mut longest = None;
+        let mut needed = None;
+
+        for s in &self.inner.xs {
+            match match_ascii(input, s.as_bytes()) {
+                Ok(len) => longest = longest.max(Some(len)),
+                Err(Mismatch::Short(n)) => needed = Some(needed.map_or(n, |m: usize| m.min(n))),
+                Err(Mismatch::Invalid) => {},
+            }
+        }
+
+        if let Some(len) = longest {
+            return Ok(input.split_at(len));
+        }
+
+        let expected = self.inner.expected();
+        match needed {
+            Some(n) => Err(ParseError::eof(input, expected).need(n)),
+            None => Err(ParseError::invalid(input, expected)),
+        }
+    }
+}
+
+impl<'a> Parse<&'a [u8]> for CharClass {
+    type Output = char;
+
+    fn parse(&self, input: &'a [u8]) -> ParseResult<Self::Output, &'a [u8]> {
+        let ch = match input.first() {
+            Some(&b) => {
+                let len = utf8_width(b).unwrap_or(1);
+                match std::str::from_utf8(&input[..len.min(input.len())]) {
+                    Ok(s) => s.chars().next(),
+                    // only a valid start of a char cut off by the end is EOF
+                    Err(e) if e.error_len().is_none() => {
+                        return Err(ParseError::eof(input, self.expected()).need(len - input.len()));
+                    },
+                    Err(_) => None,
+                }
+            },
+            None => return Err(ParseError::eof(input, self.expected()).need(1)),
+        };
+
+        match ch {
+            Some(ch) if self.contains(ch) => Ok((ch, &input[ch.len_utf8()..])),
+            _ => Err(ParseError::invalid(input, self.expected())),
+        }
+    }
+
+    #[inline]
+    fn scan(&self, input: &'a [u8]) -> Result<usize, ParseError<&'a [u8]>> {
+        let found = match self.find_byte(input, false) {
+            Some(found) => found,
+            None => return default_scan(self, input),
+        };
+
+        match found {
+            Some(idx) => Ok(idx),
+            None => Err(ParseError::eof(&input[input.len()..], self.expected())),
+        }
+    }
+}
+
+impl<'a> Parse<&'a [u8]> for TakeWhile<CharClass> {
+    type Output = &'a [u8];
+
+    #[inline]
+    fn parse(&self, input: &'a [u8]) -> ParseResult<Self::Output, &'a [u8]> {
+        let idx = match take_class(&self.f, input) {
+            Ok(idx) => idx,
+            Err(idx) => idx,
+        };
+        Ok(input.split_at(idx))
+    }
+}
+
+impl<'a> Parse<Partial<&'a [u8]>> for TakeWhile<CharClass> {
+    type Output = &'a [u8];
+
+    #[inline]
+    fn parse(&self, input: Partial<&'a [u8]>) -> ParseResult<Self::Output, Partial<&'a [u8]>> {
+        match take_class(&self.f, input.0) {
+            Ok(idx) => Ok((&input.0[..idx], Partial(&input.0[idx..]))),
+            Err(_) => Err(ParseError::end(input)),
+        }
+    }
+}
+
+// the end of the run of class members at the start of `input`, as an
+// error when the run reaches the end of the input or a truncated char
+fn take_class(class: &CharClass, input: &[u8]) -> Result<usize, usize> {
+    if let Some(found) = class.find_byte(input, true) {
+        return found.ok_or(input.len());
+    }
+
+    let mut rest = input;
+    loop {
+        match class.parse(rest) {
+            Ok((_, xs)) => rest = xs,
+            Err(e) if e.kind() == ErrorKind::EOF => return Err(input.len() - rest.len()),
+            Err(_) => return Ok(input.len() - rest.len()),
+        }
+    }
+}
+
+impl<'a, T> Parse<&'a [T]> for Seq<T>
+where
+    T: Token,
+{
+    type Output = &'a [T];
+
+    fn parse(&self, input: &'a [T]) -> ParseResult<Self::Output, &'a [T]> {
+        let len = self.seq.len();
+
+        if input.starts_with(&self.seq) {
+            return Ok((&input[0..len], &input[len..]));
+        }
+
+        match self.seq.starts_with(input) {
+            true => Err(ParseError::eof(input, self.expected.clone()).need(len - input.len())),
+            false => Err(ParseError::invalid(input, self.expected.clone())),
+        }
+    }
+
+    #[inline]
+    fn scan(&self, input: &'a [T]) -> Result<usize, ParseError<&'a [T]>> {
+        let seq = self.seq.as_slice();
+        if seq.is_empty() {
+            return Ok(0);
+        }
+
+        match T::find(input, seq) {
+            Some(idx) => Ok(idx),
+            None => Err(ParseError::eof(&input[input.len()..], self.expected.clone())),
+        }
+    }
+}
+
+impl<'a> Parse<&'a [u8]> for Whitespace {
+    type Output = &'a [u8];
+
+    fn parse(&self, input: &'a [u8]) -> ParseResult<Self::Output, &'a [u8]> {
+        match input.first() {
+            Some(b) if b.is_ascii_whitespace() => {
+                Ok((&input[0..1], &input[1..]))
+            },
+            Some(_) => Err(ParseError::invalid(input, Expected::label("whitespace"))),
+            None => Err(ParseError::eof(input, Expected::label("whitespace")).need(1)),
+        }
+    }
+}
+
+impl_partial!(&'a [u8] => CharClass, Digit, Letter, NoCase<Seq<u8>>, NoCase<OneOf<String>>, Whitespace);
+
+impl<'a> Parse<Partial<&'a [u8]>> for OneOf<String> {
+    type Output = &'a [u8];
+
+    fn parse(&self, input: Partial<&'a [u8]>) -> ParseResult<Self::Output, Partial<&'a [u8]>> {
+        let (len, _) = self.trie.longest(input, input.0, true, || self.expected())?;
+        let (xs, input) = input.take_split(len);
+        Ok((xs.0, input))
+    }
+}
+
+impl<'a> Parse<Partial<&'a [u8]>> for Indexed<OneOf<String>> {
+    type Output = usize;
+
+    fn parse(&self, input: Partial<&'a [u8]>) -> ParseResult<Self::Output, Partial<&'a [u8]>> {
+        let (len, idx) = self.inner.trie.longest(input, input.0, true, || self.inner.expected())?;
+        Ok((idx, input.take_split(len).1))
+    }
+}
+
+impl<'a, T> Parse<Partial<&'a [T]>> for One<T>
+where
+    T: Token,
+{
+    type Output = &'a [T];
+
+    fn parse(&self, input: Partial<&'a [T]>) -> ParseResult<Self::Output, Partial<&'a [T]>> {
+        parse_partial(self, input)
+    }
+
+    fn scan(&self, input: Partial<&'a [T]>) -> Result<usize, ParseError<Partial<&'a [T]>>> {
+        scan_partial(self, input)
+    }
+}
+
+impl<'a, T> Parse<Partial<&'a [T]>> for OneOf<T>
+where
+    T: Token,
+{
+    type Output = &'a [T];
+
+    fn parse(&self, input: Partial<&'a [T]>) -> ParseResult<Self::Output, Partial<&'a [T]>> {
+        parse_partial(self, input)
+    }
+
+    fn scan(&self, input: Partial<&'a [T]>) -> Result<usize, ParseError<Partial<&'a [T]>>> {
+        scan_partial(self, input)
+    }
+}
+
+impl<'a, T> Parse<Partial<&'a [T]>> for Seq<T>
+where
+    T: Token,
+{
+    type Output = &'a [T];
+
+    fn parse(&self, input: Partial<&'a [T]>) -> ParseResult<Self::Output, Partial<&'a [T]>> {
+        parse_partial(self, input)
+    }
+
+    fn scan(&self, input: Partial<&'a [T]>) -> Result<usize, ParseError<Partial<&'a [T]>>> {
+        scan_partial(self, input)
+    }
+}
 
-    fn parse(&self, input: &'a [u8]) -> Result<(Self::Output, &'a [u8]), &'a [u8]> {
-        Err(input)
+// number of bytes in a utf-8 sequence given its leading byte, if it can
+// lead one at all
+fn utf8_width(b: u8) -> Option<usize> {
+    match b {
+        0x00..=0x7f => Some(1),
+        0xc2..=0xdf => Some(2),
+        0xe0..=0xef => Some(3),
+        0xf0..=0xf4 => Some(4),
+        _ => None,
     }
 }
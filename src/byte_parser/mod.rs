@@ -1,21 +1,15 @@
-mod combinator;
 mod matcher;
+pub mod stream;
 
-pub use combinator::*;
-pub use matcher::*;
-
-use crate::combinator::{And, Map, Or};
+use crate::combinator::{And, BoxedParser, Context, ManyN, Map, Optional, Or};
 use crate::combinator::{Skip, Left, Right, SkipN};
+use crate::parser::{Parse, ParseIter};
 
-pub trait ParseByte<'a> {
-    type Output;
-
-    fn parse(&self, input: &'a [u8]) -> Result<(Self::Output, &'a [u8]), &'a [u8]>;
-
+pub trait ParseByte<'a>: Parse<&'a [u8]> {
     fn then<P>(self, other: P) -> And<Self, P>
     where
         P: ParseByte<'a>,
-        Self: Sized + 'static,
+        Self: Sized,
     {
         And::new(self, other)
     }
@@ -31,7 +25,7 @@ pub trait ParseByte<'a> {
     fn or<P>(self, other: P) -> Or<Self, P>
     where
         P: ParseByte<'a>,
-        Self: Sized + 'static,
+        Self: Sized,
     {
         Or::new(self, other)
     }
@@ -39,7 +33,7 @@ pub trait ParseByte<'a> {
     fn skip_left<P>(self, other: P) -> Skip<Left, Self, P>
     where
         P: ParseByte<'a>,
-        Self: Sized + 'static,
+        Self: Sized,
     {
         Skip::<Left, _, _>::new(self, other)
     }
@@ -47,15 +41,56 @@ pub trait ParseByte<'a> {
     fn skip_right<P>(self, other: P) -> Skip<Right, Self, P>
     where
         P: ParseByte<'a>,
-        Self: Sized + 'static,
+        Self: Sized,
     {
         Skip::<Right, _, _>::new(self, other)
     }
 
     fn skip_n(self, n: usize) -> SkipN<Self>
     where
-        Self: Sized + 'static,
+        Self: Sized,
     {
         SkipN::new(self, n)
     }
+
+    fn opt(self) -> Optional<Self>
+    where
+        Self: Sized,
+    {
+        Optional::new(self)
+    }
+
+    fn repeat(self, n: usize) -> ManyN<Self>
+    where
+        Self: Sized,
+    {
+        ManyN::new(self, n)
+    }
+
+    fn iter(&self, input: &'a [u8]) -> ParseIter<'_, Self, &'a [u8]>
+    where
+        Self: Sized,
+    {
+        ParseIter::new(self, input)
+    }
+
+    fn context(self, label: &'static str) -> Context<Self>
+    where
+        Self: Sized,
+    {
+        Context::new(self, label)
+    }
+
+    fn boxed<'p>(self) -> BoxedParser<'p, &'a [u8], Self::Output>
+    where
+        Self: Sized + 'p,
+    {
+        BoxedParser::new(self)
+    }
+}
+
+impl<'a, P> ParseByte<'a> for P
+where
+    P: Parse<&'a [u8]>,
+{
 }
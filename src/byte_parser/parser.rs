@@ -1,5 +0,0 @@
-pub trait ParseBytes<'a> {
-    type Output;
-
-    fn parse(&self, input: &'a [u8]) -> Result<(&'a [u8], Self::Output), &'a [u8]>;
-}
\ No newline at end of file
@@ -1,3 +1,10 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+
+use crate::error::ParseError;
+use crate::parser::{Input, Parse, Partial};
+
 pub trait Stream<'a> {
     fn to_stream(&'a self) -> &'a [u8];
 }
@@ -22,3 +29,173 @@ impl<'a> Stream<'a> for Vec<u8> {
         self.as_slice()
     }
 }
+
+/// Parses successive values from a [`BufRead`].
+///
+/// Data is copied into an internal buffer that grows while the parser
+/// reports [`Indeterminate`](crate::error::ErrorKind::Indeterminate) and
+/// drops each value's bytes once the value is no longer borrowed. When the
+/// error says how many more bytes it [`needed`](ParseError::needed), that
+/// many are read before the parser runs again.
+///
+/// Parsed values borrow the buffer, so each call makes one attempt and the
+/// caller loops. A parser reused across calls must accept any input
+/// lifetime, e.g. `impl for<'a> Parse<Partial<&'a [u8]>, Output = ...>`.
+///
+/// ```ignore
+/// let mut reader = Reader::new(BufReader::new(socket));
+/// loop {
+///     match reader.parse(&request)? {
+///         Status::Parsed(req) => handle(req),
+///         Status::Incomplete => continue,
+///         Status::Done => break,
+///     }
+/// }
+/// ```
+pub struct Reader<R> {
+    reader: R,
+    buf: Vec<u8>,
+    consumed: usize,
+    // bytes to read before the next attempt
+    needed: usize,
+    eof: bool,
+}
+
+/// The outcome of a single [`Reader::parse`] attempt.
+#[derive(Debug, PartialEq)]
+pub enum Status<O> {
+    Parsed(O),
+    /// More data is needed; the next call reads before retrying.
+    Incomplete,
+    /// The reader is exhausted and every buffered byte was consumed.
+    Done,
+}
+
+#[derive(Debug)]
+pub enum StreamError<I> {
+    Io(io::Error),
+    Parse(ParseError<I>),
+}
+
+impl<R> Reader<R>
+where
+    R: BufRead,
+{
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            consumed: 0,
+            needed: 0,
+            eof: false,
+        }
+    }
+
+    /// Bytes read but not yet consumed by a parse.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.consumed..]
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    pub fn parse<'b, P, O>(&'b mut self, parser: &P) -> Result<Status<O>, StreamError<&'b [u8]>>
+    where
+        P: Parse<Partial<&'b [u8]>, Output = O> + Parse<&'b [u8], Output = O>,
+    {
+        self.buf.drain(..self.consumed);
+        self.consumed = 0;
+
+        if self.buf.is_empty() {
+            self.needed = self.needed.max(1);
+        }
+        if self.needed > 0 && !self.eof {
+            self.fill(self.needed).map_err(StreamError::Io)?;
+        }
+        self.needed = 0;
+
+        if self.buf.is_empty() && self.eof {
+            return Ok(Status::Done);
+        }
+
+        // once the reader is exhausted the buffer is all the input there is
+        let res = match self.eof {
+            true => parser.parse(&self.buf[..]),
+            false => Parse::<Partial<&'b [u8]>>::parse(parser, Partial(&self.buf[..]))
+                .map(|(res, rest)| (res, rest.0))
+                .map_err(|e| e.into_inner()),
+        };
+
+        match res {
+            Ok((res, rest)) => {
+                self.consumed = self.buf.len() - rest.len();
+                Ok(Status::Parsed(res))
+            },
+            Err(e) if e.is_incomplete() => {
+                self.needed = e.needed().unwrap_or(1);
+                Ok(Status::Incomplete)
+            },
+            Err(e) => Err(StreamError::Parse(e)),
+        }
+    }
+
+    // reads until at least `n` more bytes are buffered or the reader ends
+    fn fill(&mut self, n: usize) -> io::Result<()> {
+        let target = self.buf.len() + n;
+
+        while self.buf.len() < target {
+            let chunk = match self.reader.fill_buf() {
+                Ok(chunk) => chunk,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            let len = chunk.len();
+            if len == 0 {
+                self.eof = true;
+                break;
+            }
+
+            self.buf.extend_from_slice(chunk);
+            self.reader.consume(len);
+        }
+
+        Ok(())
+    }
+}
+
+impl<R> Reader<BufReader<R>>
+where
+    R: Read,
+{
+    pub fn from_read(reader: R) -> Self {
+        Self::new(BufReader::new(reader))
+    }
+}
+
+impl<I> From<io::Error> for StreamError<I> {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl<I> fmt::Display for StreamError<I>
+where
+    I: Input,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<I> Error for StreamError<I>
+where
+    I: Input + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+}
@@ -0,0 +1,164 @@
+use crate::error::{ErrorKind, ParseError};
+use crate::parser::{Input, Parse, ParseResult};
+
+impl<I, P> Parse<I> for Box<P>
+where
+    P: Parse<I> + ?Sized,
+{
+    type Output = P::Output;
+
+    #[inline]
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        (**self).parse(input)
+    }
+
+    #[inline]
+    fn scan(&self, input: I) -> Result<usize, ParseError<I>>
+    where
+        I: Input,
+    {
+        (**self).scan(input)
+    }
+}
+
+/// A parser behind a trait object.
+///
+/// Erases the combinator type so parsers can be stored in structs or picked
+/// at runtime, at the cost of a virtual call per parse.
+pub struct BoxedParser<'p, I, O> {
+    p: Box<dyn Parse<I, Output = O> + 'p>,
+}
+
+impl<'p, I, O> BoxedParser<'p, I, O> {
+    pub fn new<P>(p: P) -> Self
+    where
+        P: Parse<I, Output = O> + 'p,
+    {
+        Self {
+            p: Box::new(p),
+        }
+    }
+}
+
+impl<'p, I, O> Parse<I> for BoxedParser<'p, I, O> {
+    type Output = O;
+
+    #[inline]
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        self.p.parse(input)
+    }
+
+    #[inline]
+    fn scan(&self, input: I) -> Result<usize, ParseError<I>>
+    where
+        I: Input,
+    {
+        self.p.scan(input)
+    }
+}
+
+/// A boxed parser that accepts `&str` input of any lifetime.
+///
+/// A [`BoxedParser`] names its input type, so a grammar stored in a struct
+/// can only parse inputs that live as long as each other. This form can be
+/// kept and reused across inputs, as long as the output doesn't borrow from
+/// them.
+pub struct BoxedStrParser<'p, O> {
+    p: Box<dyn for<'i> Parse<&'i str, Output = O> + 'p>,
+}
+
+impl<'p, O> BoxedStrParser<'p, O> {
+    pub fn new<P>(p: P) -> Self
+    where
+        P: for<'i> Parse<&'i str, Output = O> + 'p,
+    {
+        Self {
+            p: Box::new(p),
+        }
+    }
+}
+
+impl<'i, 'p, O> Parse<&'i str> for BoxedStrParser<'p, O> {
+    type Output = O;
+
+    #[inline]
+    fn parse(&self, input: &'i str) -> ParseResult<Self::Output, &'i str> {
+        self.p.parse(input)
+    }
+
+    #[inline]
+    fn scan(&self, input: &'i str) -> Result<usize, ParseError<&'i str>> {
+        self.p.scan(input)
+    }
+}
+
+/// A boxed parser that accepts slices of any lifetime, see [`BoxedStrParser`].
+pub struct BoxedSliceParser<'p, T, O> {
+    p: Box<dyn for<'i> Parse<&'i [T], Output = O> + 'p>,
+}
+
+impl<'p, T, O> BoxedSliceParser<'p, T, O> {
+    pub fn new<P>(p: P) -> Self
+    where
+        P: for<'i> Parse<&'i [T], Output = O> + 'p,
+    {
+        Self {
+            p: Box::new(p),
+        }
+    }
+}
+
+impl<'i, 'p, T, O> Parse<&'i [T]> for BoxedSliceParser<'p, T, O> {
+    type Output = O;
+
+    #[inline]
+    fn parse(&self, input: &'i [T]) -> ParseResult<Self::Output, &'i [T]> {
+        self.p.parse(input)
+    }
+
+    #[inline]
+    fn scan(&self, input: &'i [T]) -> Result<usize, ParseError<&'i [T]>> {
+        self.p.scan(input)
+    }
+}
+
+/// Tries a list of parsers in order, returning the first success.
+///
+/// The runtime counterpart of [`Alt`](super::Alt), for alternatives that
+/// aren't known until the grammar is built.
+pub struct Choice<P> {
+    pub(crate) ps: Vec<P>,
+}
+
+impl<P> Choice<P> {
+    pub fn new(ps: Vec<P>) -> Self {
+        Self {
+            ps,
+        }
+    }
+}
+
+impl<I, P> Parse<I> for Choice<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let mut err: Option<ParseError<I>> = None;
+
+        for p in &self.ps {
+            match p.parse(input) {
+                Ok(res) => return Ok(res),
+                Err(e) if !e.is_recoverable() => return Err(e),
+                Err(e) => err = Some(match err {
+                    Some(err) => err.merge(e),
+                    None => e,
+                }),
+            }
+        }
+
+        Err(err.unwrap_or_else(|| ParseError::new(ErrorKind::Invalid, input)))
+    }
+}
@@ -0,0 +1,1102 @@
+mod boxed;
+mod precedence;
+mod recursive;
+mod tuple;
+
+pub use boxed::{BoxedParser, BoxedSliceParser, BoxedStrParser, Choice};
+pub use precedence::{Assoc, ChainL1, ChainR1, Precedence};
+pub use recursive::Recursive;
+pub use tuple::{Alt, Sequence};
+
+use crate::error::{Expected, ParseError};
+use crate::parser::{Input, Parse, ParseResult};
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+pub struct State<F> {
+    pub(crate) init: F,
+}
+
+impl<F> State<F> {
+    pub fn new(init: F) -> Self {
+        Self {
+            init,
+        }
+    }
+}
+
+impl<I, F, T> Parse<I> for State<F>
+where
+    F: Fn() -> T + Clone,
+{
+    type Output = T;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let state = (self.init)();
+        Ok((state, input))
+    }
+}
+
+pub struct Map<P, F> {
+    pub(crate) p: P,
+    pub(crate) f: F,
+}
+
+impl<P, F> Map<P, F> {
+    pub fn new(p: P, f: F) -> Self {
+        Self {
+            p,
+            f
+        }
+    }
+}
+
+impl<I, P, F, B> Parse<I> for Map<P, F>
+where
+    P: Parse<I>,
+    F: Fn(P::Output) -> B,
+{
+    type Output = B;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (a, input) = self.p.parse(input)?;
+        let b = (self.f)(a);
+        Ok((b, input))
+    }
+}
+
+// zero or more
+pub struct Many0<P> {
+    pub(crate) p: P
+}
+
+impl<P> Many0<P> {
+    pub fn new(p: P) -> Self {
+        Self {
+            p
+        }
+    }
+}
+
+impl<I, P> Parse<I> for Many0<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let mut acc = Vec::new();
+        let mut cursor = input;
+
+        loop {
+            match self.p.parse(cursor) {
+                Ok((_, input)) if input.input_len() == cursor.input_len() => break,
+                Ok((res, input)) => {
+                    acc.push(res);
+                    cursor = input;
+                },
+                Err(e) if !e.is_recoverable() => return Err(e),
+                Err(_) => break,
+            }
+        }
+
+        Ok((acc, cursor))
+    }
+}
+
+// one or more
+pub struct Many1<P> {
+    pub(crate) p: P
+}
+
+impl<P> Many1<P> {
+    pub fn new(p: P) -> Self {
+        Self {
+            p
+        }
+    }
+}
+
+impl<I, P> Parse<I> for Many1<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let mut acc = Vec::new();
+
+        let (res, mut cursor) = self.p.parse(input)?;
+        acc.push(res);
+
+        loop {
+            match self.p.parse(cursor) {
+                Ok((_, input)) if input.input_len() == cursor.input_len() => break,
+                Ok((res, input)) => {
+                    acc.push(res);
+                    cursor = input;
+                },
+                Err(e) if !e.is_recoverable() => return Err(e),
+                Err(_) => break,
+            }
+        }
+
+        Ok((acc, cursor))
+    }
+}
+
+impl<P> Many0<P> {
+    /// Collects into any `Default + Extend` container instead of a `Vec`.
+    pub fn collect<C>(self) -> Collect<P, C> {
+        Collect::new(self.p, 0)
+    }
+}
+
+impl<P> Many1<P> {
+    pub fn collect<C>(self) -> Collect<P, C> {
+        Collect::new(self.p, 1)
+    }
+}
+
+pub struct Collect<P, C> {
+    pub(crate) p: P,
+    pub(crate) min: usize,
+    _phantom: PhantomData<fn() -> C>,
+}
+
+impl<P, C> Collect<P, C> {
+    pub fn new(p: P, min: usize) -> Self {
+        Self {
+            p,
+            min,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, P, C> Parse<I> for Collect<P, C>
+where
+    I: Input,
+    P: Parse<I>,
+    C: Default + Extend<P::Output>,
+{
+    type Output = C;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let mut acc = C::default();
+        let mut count = 0;
+        let mut cursor = input;
+
+        loop {
+            match self.p.parse(cursor) {
+                Ok((_, xs)) if xs.input_len() == cursor.input_len() && count >= self.min => break,
+                Ok((res, xs)) => {
+                    acc.extend(Some(res));
+                    count += 1;
+                    cursor = xs;
+                },
+                Err(e) if !e.is_recoverable() || count < self.min => return Err(e),
+                Err(_) => break,
+            }
+        }
+
+        Ok((acc, cursor))
+    }
+}
+
+// zero or more, folded into a single value
+pub struct FoldMany0<P, Init, F> {
+    pub(crate) p: P,
+    pub(crate) init: Init,
+    pub(crate) f: F,
+}
+
+impl<P, Init, F> FoldMany0<P, Init, F> {
+    pub fn new(p: P, init: Init, f: F) -> Self {
+        Self {
+            p,
+            init,
+            f,
+        }
+    }
+}
+
+impl<I, P, Init, F, B> Parse<I> for FoldMany0<P, Init, F>
+where
+    I: Input,
+    P: Parse<I>,
+    Init: Fn() -> B,
+    F: Fn(B, P::Output) -> B,
+{
+    type Output = B;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        fold(&self.p, (self.init)(), &self.f, input)
+    }
+}
+
+// one or more, folded into a single value
+pub struct FoldMany1<P, Init, F> {
+    pub(crate) p: P,
+    pub(crate) init: Init,
+    pub(crate) f: F,
+}
+
+impl<P, Init, F> FoldMany1<P, Init, F> {
+    pub fn new(p: P, init: Init, f: F) -> Self {
+        Self {
+            p,
+            init,
+            f,
+        }
+    }
+}
+
+impl<I, P, Init, F, B> Parse<I> for FoldMany1<P, Init, F>
+where
+    I: Input,
+    P: Parse<I>,
+    Init: Fn() -> B,
+    F: Fn(B, P::Output) -> B,
+{
+    type Output = B;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (res, cursor) = self.p.parse(input)?;
+        let acc = (self.f)((self.init)(), res);
+        fold(&self.p, acc, &self.f, cursor)
+    }
+}
+
+fn fold<I, P, F, B>(p: &P, mut acc: B, f: &F, mut cursor: I) -> ParseResult<B, I>
+where
+    I: Input,
+    P: Parse<I>,
+    F: Fn(B, P::Output) -> B,
+{
+    loop {
+        match p.parse(cursor) {
+            Ok((_, xs)) if xs.input_len() == cursor.input_len() => break,
+            Ok((res, xs)) => {
+                acc = f(acc, res);
+                cursor = xs;
+            },
+            Err(e) if !e.is_recoverable() => return Err(e),
+            Err(_) => break,
+        }
+    }
+
+    Ok((acc, cursor))
+}
+
+// how many times `p` matches, without keeping the outputs
+pub struct Many0Count<P> {
+    pub(crate) p: P
+}
+
+impl<P> Many0Count<P> {
+    pub fn new(p: P) -> Self {
+        Self {
+            p
+        }
+    }
+}
+
+impl<I, P> Parse<I> for Many0Count<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = usize;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        fold(&self.p, 0, &|n, _| n + 1, input)
+    }
+}
+
+// n
+pub struct ManyN<P> {
+    pub(crate) n: usize,
+    pub(crate) p: P,
+}
+
+impl<P> ManyN<P> {
+    pub fn new(p: P, n: usize) -> Self {
+        Self {
+            n,
+            p,
+        }
+    }
+}
+
+impl<I, P> Parse<I> for ManyN<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let mut acc = Vec::with_capacity(self.n);
+        let mut cursor = input;
+
+        for _ in 0..self.n {
+            let (res, xs) = self.p.parse(cursor)?;
+            acc.push(res);
+            cursor = xs;
+        }
+
+        Ok((acc, cursor))
+    }
+}
+
+// zero or more, separated by `sep`
+pub struct SepBy<P, S> {
+    pub(crate) p: P,
+    pub(crate) sep: S,
+}
+
+impl<P, S> SepBy<P, S> {
+    pub fn new(p: P, sep: S) -> Self {
+        Self {
+            p,
+            sep,
+        }
+    }
+}
+
+impl<I, P, S> Parse<I> for SepBy<P, S>
+where
+    I: Input,
+    P: Parse<I>,
+    S: Parse<I>,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        match self.p.parse(input) {
+            Ok((res, cursor)) => sep_tail(&self.p, &self.sep, vec![res], cursor),
+            Err(e) if !e.is_recoverable() => Err(e),
+            Err(_) => Ok((Vec::new(), input)),
+        }
+    }
+}
+
+// one or more, separated by `sep`
+pub struct SepBy1<P, S> {
+    pub(crate) p: P,
+    pub(crate) sep: S,
+}
+
+impl<P, S> SepBy1<P, S> {
+    pub fn new(p: P, sep: S) -> Self {
+        Self {
+            p,
+            sep,
+        }
+    }
+}
+
+impl<I, P, S> Parse<I> for SepBy1<P, S>
+where
+    I: Input,
+    P: Parse<I>,
+    S: Parse<I>,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (res, cursor) = self.p.parse(input)?;
+        sep_tail(&self.p, &self.sep, vec![res], cursor)
+    }
+}
+
+// the `sep p` pairs after the first item; a separator with no item after it
+// is left unconsumed
+fn sep_tail<I, P, S>(p: &P, sep: &S, mut acc: Vec<P::Output>, mut cursor: I) -> ParseResult<Vec<P::Output>, I>
+where
+    I: Input,
+    P: Parse<I>,
+    S: Parse<I>,
+{
+    loop {
+        let xs = match sep.parse(cursor) {
+            Ok((_, xs)) => xs,
+            Err(e) if !e.is_recoverable() => return Err(e),
+            Err(_) => break,
+        };
+
+        match p.parse(xs) {
+            // stop rather than loop forever if neither consumes anything
+            Ok((_, xs)) if xs.input_len() == cursor.input_len() => break,
+            Ok((res, xs)) => {
+                acc.push(res);
+                cursor = xs;
+            },
+            Err(e) if !e.is_recoverable() => return Err(e),
+            Err(_) => break,
+        }
+    }
+
+    Ok((acc, cursor))
+}
+
+// zero or more, separated by `sep` with an optional trailing `sep`
+pub struct SepEndBy<P, S> {
+    pub(crate) p: P,
+    pub(crate) sep: S,
+}
+
+impl<P, S> SepEndBy<P, S> {
+    pub fn new(p: P, sep: S) -> Self {
+        Self {
+            p,
+            sep,
+        }
+    }
+}
+
+impl<I, P, S> Parse<I> for SepEndBy<P, S>
+where
+    I: Input,
+    P: Parse<I>,
+    S: Parse<I>,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let mut acc = Vec::new();
+        let mut cursor = input;
+
+        loop {
+            match self.p.parse(cursor) {
+                Ok((res, xs)) => {
+                    acc.push(res);
+                    cursor = xs;
+                },
+                Err(e) if !e.is_recoverable() => return Err(e),
+                Err(_) => break,
+            }
+
+            match self.sep.parse(cursor) {
+                Ok((_, xs)) if xs.input_len() == cursor.input_len() => break,
+                Ok((_, xs)) => cursor = xs,
+                Err(e) if !e.is_recoverable() => return Err(e),
+                Err(_) => break,
+            }
+        }
+
+        Ok((acc, cursor))
+    }
+}
+
+// zero or more, each followed by `sep`
+pub struct EndBy<P, S> {
+    pub(crate) p: P,
+    pub(crate) sep: S,
+}
+
+impl<P, S> EndBy<P, S> {
+    pub fn new(p: P, sep: S) -> Self {
+        Self {
+            p,
+            sep,
+        }
+    }
+}
+
+impl<I, P, S> Parse<I> for EndBy<P, S>
+where
+    I: Input,
+    P: Parse<I>,
+    S: Parse<I>,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let mut acc = Vec::new();
+        let mut cursor = input;
+
+        loop {
+            let (res, xs) = match self.p.parse(cursor) {
+                Ok(ok) => ok,
+                Err(e) if !e.is_recoverable() => return Err(e),
+                Err(_) => break,
+            };
+
+            match self.sep.parse(xs) {
+                Ok((_, xs)) if xs.input_len() == cursor.input_len() => break,
+                Ok((_, xs)) => {
+                    acc.push(res);
+                    cursor = xs;
+                },
+                Err(e) if !e.is_recoverable() => return Err(e),
+                Err(_) => break,
+            }
+        }
+
+        Ok((acc, cursor))
+    }
+}
+
+// between `min` and `max` times, inclusive
+pub struct ManyMN<P> {
+    pub(crate) p: P,
+    pub(crate) min: usize,
+    pub(crate) max: usize,
+}
+
+impl<P> ManyMN<P> {
+    /// # Panics
+    ///
+    /// If `range` is empty, such as `3..=1` or `..0`.
+    pub fn new(p: P, range: impl RangeBounds<usize>) -> Self {
+        let min = match range.start_bound() {
+            Bound::Included(&n) => Some(n),
+            Bound::Excluded(&n) => n.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let max = match range.end_bound() {
+            Bound::Included(&n) => Some(n),
+            Bound::Excluded(&n) => n.checked_sub(1),
+            Bound::Unbounded => Some(usize::MAX),
+        };
+
+        let (min, max) = match (min, max) {
+            (Some(min), Some(max)) if min <= max => (min, max),
+            _ => panic!("repeat range is empty"),
+        };
+
+        Self {
+            p,
+            min,
+            max,
+        }
+    }
+}
+
+impl<I, P> Parse<I> for ManyMN<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = Vec<P::Output>;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let mut acc = Vec::new();
+        let mut cursor = input;
+
+        while acc.len() < self.max {
+            match self.p.parse(cursor) {
+                Ok((_, xs)) if xs.input_len() == cursor.input_len() && acc.len() >= self.min => break,
+                Ok((res, xs)) => {
+                    acc.push(res);
+                    cursor = xs;
+                },
+                Err(e) if !e.is_recoverable() || acc.len() < self.min => return Err(e),
+                Err(_) => break,
+            }
+        }
+
+        Ok((acc, cursor))
+    }
+}
+
+// `p` repeated until `end` matches, returning both
+pub struct ManyTill<P, E> {
+    pub(crate) p: P,
+    pub(crate) end: E,
+}
+
+impl<P, E> ManyTill<P, E> {
+    pub fn new(p: P, end: E) -> Self {
+        Self {
+            p,
+            end,
+        }
+    }
+}
+
+impl<I, P, E> Parse<I> for ManyTill<P, E>
+where
+    I: Input,
+    P: Parse<I>,
+    E: Parse<I>,
+{
+    type Output = (Vec<P::Output>, E::Output);
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let mut acc = Vec::new();
+        let mut cursor = input;
+
+        loop {
+            let e1 = match self.end.parse(cursor) {
+                Ok((end, xs)) => return Ok(((acc, end), xs)),
+                Err(e) if !e.is_recoverable() => return Err(e),
+                Err(e) => e,
+            };
+
+            match self.p.parse(cursor) {
+                Ok((_, xs)) if xs.input_len() == cursor.input_len() => return Err(e1),
+                Ok((res, xs)) => {
+                    acc.push(res);
+                    cursor = xs;
+                },
+                Err(e2) if !e2.is_recoverable() => return Err(e2),
+                Err(e2) => return Err(e1.merge(e2)),
+            }
+        }
+    }
+}
+
+pub struct Optional<P> {
+    pub(crate) p: P
+}
+
+impl<P> Optional<P> {
+    pub fn new(p: P) -> Self {
+        Self {
+            p
+        }
+    }
+}
+
+impl<I, P> Parse<I> for Optional<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = Option<P::Output>;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        match self.p.parse(input) {
+            Ok((res, input)) => Ok((Some(res), input)),
+            Err(e) if !e.is_recoverable() => Err(e),
+            Err(_) => Ok((None, input)),
+        }
+    }
+}
+
+pub struct And<P1, P2> {
+    pub(crate) p1: P1,
+    pub(crate) p2: P2,
+}
+
+impl<P1, P2> And<P1, P2> {
+    pub fn new(p1: P1, p2: P2) -> Self {
+        Self {
+            p1,
+            p2
+        }
+    }
+}
+
+impl<I, P1, P2> Parse<I> for And<P1, P2>
+where
+    P1: Parse<I>,
+    P2: Parse<I>,
+{
+    type Output = (P1::Output, P2::Output);
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (a, input) = self.p1.parse(input)?;
+        let (b, input) = self.p2.parse(input)?;
+        Ok(((a, b), input))
+    }
+}
+
+pub struct Or<P1, P2> {
+    pub(crate) p1: P1,
+    pub(crate) p2: P2,
+}
+
+impl<P1, P2> Or<P1, P2> {
+    pub fn new(p1: P1, p2: P2) -> Self {
+        Self {
+            p1,
+            p2,
+        }
+    }
+}
+
+impl<I, P1, P2, O> Parse<I> for Or<P1, P2>
+where
+    I: Input,
+    P1: Parse<I, Output = O>,
+    P2: Parse<I, Output = O>,
+{
+    type Output = O;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        match self.p1.parse(input) {
+            Ok(res) => Ok(res),
+            Err(e1) if !e1.is_recoverable() => Err(e1),
+            Err(e1) => match self.p2.parse(input) {
+                Ok(res) => Ok(res),
+                Err(e2) if !e2.is_recoverable() => Err(e2),
+                Err(e2) => Err(e1.merge(e2)),
+            },
+        }
+    }
+}
+
+pub struct Skip<S: SkipDirection, P1, P2> {
+    pub(crate) p1: P1,
+    pub(crate) p2: P2,
+    _phantom: PhantomData<S>
+}
+
+impl<P1, P2> Skip<Left, P1, P2> {
+    pub fn new(p1: P1, p2: P2) -> Self {
+        Self {
+            p1,
+            p2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<P1, P2> Skip<Right, P1, P2> {
+    pub fn new(p1: P1, p2: P2) -> Self {
+        Self {
+            p1,
+            p2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<I, P1, P2> Parse<I> for Skip<Left, P1, P2>
+where
+    P1: Parse<I>,
+    P2: Parse<I>,
+{
+    type Output = P2::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (_, input) = self.p1.parse(input)?;
+        self.p2.parse(input)
+    }
+}
+
+impl<I, P1, P2> Parse<I> for Skip<Right, P1, P2>
+where
+    P1: Parse<I>,
+    P2: Parse<I>,
+{
+    type Output = P1::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (a, input) = self.p1.parse(input)?;
+        let (_, input) = self.p2.parse(input)?;
+        Ok((a, input))
+    }
+}
+
+pub trait SkipDirection {
+}
+
+pub struct Left;
+
+impl SkipDirection for Left {
+}
+
+pub struct Right;
+
+impl SkipDirection for Right {
+}
+
+// `p` between `open` and `close`, keeping only `p`
+pub struct Delimited<O, P, C> {
+    pub(crate) open: O,
+    pub(crate) p: P,
+    pub(crate) close: C,
+}
+
+impl<O, P, C> Delimited<O, P, C> {
+    pub fn new(open: O, p: P, close: C) -> Self {
+        Self {
+            open,
+            p,
+            close,
+        }
+    }
+}
+
+impl<I, O, P, C> Parse<I> for Delimited<O, P, C>
+where
+    O: Parse<I>,
+    P: Parse<I>,
+    C: Parse<I>,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (_, input) = self.open.parse(input)?;
+        let (res, input) = self.p.parse(input)?;
+        let (_, input) = self.close.parse(input)?;
+        Ok((res, input))
+    }
+}
+
+// `p1` and `p2` around a `sep` that is dropped
+pub struct SeparatedPair<P1, S, P2> {
+    pub(crate) p1: P1,
+    pub(crate) sep: S,
+    pub(crate) p2: P2,
+}
+
+impl<P1, S, P2> SeparatedPair<P1, S, P2> {
+    pub fn new(p1: P1, sep: S, p2: P2) -> Self {
+        Self {
+            p1,
+            sep,
+            p2,
+        }
+    }
+}
+
+impl<I, P1, S, P2> Parse<I> for SeparatedPair<P1, S, P2>
+where
+    P1: Parse<I>,
+    S: Parse<I>,
+    P2: Parse<I>,
+{
+    type Output = (P1::Output, P2::Output);
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (a, input) = self.p1.parse(input)?;
+        let (_, input) = self.sep.parse(input)?;
+        let (b, input) = self.p2.parse(input)?;
+        Ok(((a, b), input))
+    }
+}
+
+pub struct SkipN<P> {
+    pub(crate) p: P,
+    pub(crate) n: usize,
+}
+
+impl<P> SkipN<P> {
+    pub fn new(p: P, n: usize) -> Self {
+        Self {
+            p,
+            n
+        }
+    }
+}
+
+impl<I, P> Parse<I> for SkipN<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (res, xs) = self.p.parse(input)?;
+
+        if self.n > xs.input_len() {
+            return Err(ParseError::end(xs).need(self.n - xs.input_len()));
+        }
+        if !xs.is_boundary(self.n) {
+            return Err(ParseError::invalid(xs, Expected::label("char boundary")));
+        }
+
+        let (_, xs) = xs.take_split(self.n);
+        Ok((res, xs))
+    }
+}
+
+// `p` without consuming any input
+pub struct Peek<P> {
+    pub(crate) p: P
+}
+
+impl<P> Peek<P> {
+    pub fn new(p: P) -> Self {
+        Self {
+            p
+        }
+    }
+}
+
+impl<I, P> Parse<I> for Peek<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (res, _) = self.p.parse(input)?;
+        Ok((res, input))
+    }
+}
+
+// succeeds, consuming nothing, only where `p` fails
+pub struct Not<P> {
+    pub(crate) p: P
+}
+
+impl<P> Not<P> {
+    pub fn new(p: P) -> Self {
+        Self {
+            p
+        }
+    }
+}
+
+impl<I, P> Parse<I> for Not<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = ();
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        match self.p.parse(input) {
+            // the inner parser succeeded, so it has no expected set to borrow
+            Ok(_) => Err(ParseError::invalid(input, Expected::label("something else"))),
+            Err(e) if !e.is_recoverable() => Err(e),
+            Err(_) => Ok(((), input)),
+        }
+    }
+}
+
+// commits to `p`: its failures are fatal, so no enclosing `Or` retries
+pub struct Cut<P> {
+    pub(crate) p: P
+}
+
+impl<P> Cut<P> {
+    pub fn new(p: P) -> Self {
+        Self {
+            p
+        }
+    }
+}
+
+impl<I, P> Parse<I> for Cut<P>
+where
+    P: Parse<I>,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        self.p.parse(input).map_err(|e| e.cut())
+    }
+}
+
+pub struct Context<P> {
+    pub(crate) p: P,
+    pub(crate) label: &'static str,
+}
+
+impl<P> Context<P> {
+    pub fn new(p: P, label: &'static str) -> Self {
+        Self {
+            p,
+            label,
+        }
+    }
+}
+
+impl<I, P> Parse<I> for Context<P>
+where
+    P: Parse<I>,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        self.p.parse(input).map_err(|e| e.within(self.label))
+    }
+}
+
+pub struct TakeUntil<P> {
+    pub(crate) p: P
+}
+
+impl<P> TakeUntil<P> {
+    pub fn new(p: P) -> Self {
+        Self {
+            p
+        }
+    }
+}
+
+impl<I, P> Parse<I> for TakeUntil<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = I::Slice;
+
+    #[inline]
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let idx = self.p.scan(input)?;
+        let (xs, input) = input.take_split(idx);
+        Ok((xs.into_slice(), input))
+    }
+}
+
+// take until, consuming and returning the terminator as well
+pub struct TakeThrough<P> {
+    pub(crate) p: P
+}
+
+impl<P> TakeThrough<P> {
+    pub fn new(p: P) -> Self {
+        Self {
+            p
+        }
+    }
+}
+
+impl<I, P> Parse<I> for TakeThrough<P>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Output = (I::Slice, P::Output);
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let idx = self.p.scan(input)?;
+        let (xs, input) = input.take_split(idx);
+        let (end, input) = self.p.parse(input)?;
+        Ok(((xs.into_slice(), end), input))
+    }
+}
+
+pub struct TakeWhile<F> {
+    pub(crate) f: F,
+}
+
+impl<F> TakeWhile<F> {
+    pub fn new(f: F) -> Self {
+        Self {
+            f
+        }
+    }
+}
+
+impl<I, F> Parse<I> for TakeWhile<F>
+where
+    I: Input,
+    F: Fn(I::Item) -> bool,
+{
+    type Output = I::Slice;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let idx = match input.position(|x| !(self.f)(x)) {
+            Some(idx) => idx,
+            None if input.is_partial() => return Err(ParseError::end(input)),
+            None => input.input_len(),
+        };
+
+        let (xs, input) = input.take_split(idx);
+        Ok((xs.into_slice(), input))
+    }
+}
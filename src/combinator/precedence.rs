@@ -0,0 +1,320 @@
+use std::rc::Rc;
+
+use crate::combinator::{BoxedParser, Map};
+use crate::error::ParseError;
+use crate::parser::{Input, Parse, ParseResult};
+
+// one or more of `p` separated by `op`, folded from the left
+pub struct ChainL1<P, Op> {
+    pub(crate) p: P,
+    pub(crate) op: Op,
+}
+
+impl<P, Op> ChainL1<P, Op> {
+    pub fn new(p: P, op: Op) -> Self {
+        Self {
+            p,
+            op,
+        }
+    }
+}
+
+impl<I, P, Op, F> Parse<I> for ChainL1<P, Op>
+where
+    I: Input,
+    P: Parse<I>,
+    Op: Parse<I, Output = F>,
+    F: Fn(P::Output, P::Output) -> P::Output,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (mut acc, mut cursor) = self.p.parse(input)?;
+
+        while let Some((f, rhs, xs)) = chain_next(&self.p, &self.op, cursor)? {
+            acc = f(acc, rhs);
+            cursor = xs;
+        }
+
+        Ok((acc, cursor))
+    }
+}
+
+// one or more of `p` separated by `op`, folded from the right
+pub struct ChainR1<P, Op> {
+    pub(crate) p: P,
+    pub(crate) op: Op,
+}
+
+impl<P, Op> ChainR1<P, Op> {
+    pub fn new(p: P, op: Op) -> Self {
+        Self {
+            p,
+            op,
+        }
+    }
+}
+
+impl<I, P, Op, F> Parse<I> for ChainR1<P, Op>
+where
+    I: Input,
+    P: Parse<I>,
+    Op: Parse<I, Output = F>,
+    F: Fn(P::Output, P::Output) -> P::Output,
+{
+    type Output = P::Output;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let (first, mut cursor) = self.p.parse(input)?;
+        let mut rest = vec![];
+
+        while let Some((f, rhs, xs)) = chain_next(&self.p, &self.op, cursor)? {
+            rest.push((f, rhs));
+            cursor = xs;
+        }
+
+        // pair each operator with the operand on its left, then fold back
+        let mut lhs = first;
+        let mut pending = Vec::with_capacity(rest.len());
+        for (f, rhs) in rest {
+            pending.push((lhs, f));
+            lhs = rhs;
+        }
+
+        let acc = pending.into_iter().rev().fold(lhs, |acc, (x, f)| f(x, acc));
+        Ok((acc, cursor))
+    }
+}
+
+// an operator, the operand after it and the rest of the input
+type Link<F, O, I> = Option<(F, O, I)>;
+
+// the next operator and operand, or none if either is missing
+fn chain_next<I, P, Op>(p: &P, op: &Op, cursor: I) -> Result<Link<Op::Output, P::Output, I>, ParseError<I>>
+where
+    I: Input,
+    P: Parse<I>,
+    Op: Parse<I>,
+{
+    let (f, xs) = match op.parse(cursor) {
+        Ok(res) => res,
+        Err(e) if !e.is_recoverable() => return Err(e),
+        Err(_) => return Ok(None),
+    };
+
+    match p.parse(xs) {
+        Ok((_, xs)) if xs.input_len() == cursor.input_len() => Ok(None),
+        Ok((rhs, xs)) => Ok(Some((f, rhs, xs))),
+        Err(e) if !e.is_recoverable() => Err(e),
+        Err(_) => Ok(None),
+    }
+}
+
+/// How an infix operator groups with others at the same precedence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Assoc {
+    Left,
+    Right,
+}
+
+// an operator's output already bound into its closure
+type Apply1<'p, O> = Box<dyn FnOnce(O) -> O + 'p>;
+type Apply2<'p, O> = Box<dyn FnOnce(O, O) -> O + 'p>;
+
+struct Unary<'p, I, O> {
+    op: BoxedParser<'p, I, Apply1<'p, O>>,
+    bp: u32,
+}
+
+struct Binary<'p, I, O> {
+    op: BoxedParser<'p, I, Apply2<'p, O>>,
+    lbp: u32,
+    rbp: u32,
+}
+
+/// An operator-precedence expression parser.
+///
+/// Built from an atom parser plus tables of prefix, infix and postfix
+/// operators, each with a precedence where higher binds tighter and a
+/// closure that builds the result from the operator's output and its
+/// operands. Operators of a kind are tried in the
+/// order they were added, so add `**` before `*`. Once an operator has
+/// matched, a missing operand is an error rather than the end of the
+/// expression.
+pub struct Precedence<'p, I, O> {
+    atom: BoxedParser<'p, I, O>,
+    prefix: Vec<Unary<'p, I, O>>,
+    infix: Vec<Binary<'p, I, O>>,
+    postfix: Vec<Unary<'p, I, O>>,
+}
+
+impl<'p, I, O> Precedence<'p, I, O> {
+    pub fn new<P>(atom: P) -> Self
+    where
+        P: Parse<I, Output = O> + 'p,
+    {
+        Self {
+            atom: BoxedParser::new(atom),
+            prefix: vec![],
+            infix: vec![],
+            postfix: vec![],
+        }
+    }
+
+    pub fn prefix<P, F>(mut self, op: P, prec: u32, f: F) -> Self
+    where
+        P: Parse<I> + 'p,
+        P::Output: 'p,
+        F: Fn(P::Output, O) -> O + 'p,
+    {
+        let f = Rc::new(f);
+        let op = Map::new(op, move |x| {
+            let f = Rc::clone(&f);
+            Box::new(move |rhs| f(x, rhs)) as Apply1<'p, O>
+        });
+
+        self.prefix.push(Unary {
+            op: BoxedParser::new(op),
+            bp: binding_power(prec).1,
+        });
+        self
+    }
+
+    pub fn infix<P, F>(mut self, op: P, prec: u32, assoc: Assoc, f: F) -> Self
+    where
+        P: Parse<I> + 'p,
+        P::Output: 'p,
+        F: Fn(O, P::Output, O) -> O + 'p,
+    {
+        let (lbp, rbp) = match (assoc, binding_power(prec)) {
+            (Assoc::Left, (lo, hi)) => (lo, hi),
+            (Assoc::Right, (lo, hi)) => (hi, lo),
+        };
+
+        let f = Rc::new(f);
+        let op = Map::new(op, move |x| {
+            let f = Rc::clone(&f);
+            Box::new(move |lhs, rhs| f(lhs, x, rhs)) as Apply2<'p, O>
+        });
+
+        self.infix.push(Binary {
+            op: BoxedParser::new(op),
+            lbp,
+            rbp,
+        });
+        self
+    }
+
+    pub fn postfix<P, F>(mut self, op: P, prec: u32, f: F) -> Self
+    where
+        P: Parse<I> + 'p,
+        P::Output: 'p,
+        F: Fn(O, P::Output) -> O + 'p,
+    {
+        let f = Rc::new(f);
+        let op = Map::new(op, move |x| {
+            let f = Rc::clone(&f);
+            Box::new(move |lhs| f(lhs, x)) as Apply1<'p, O>
+        });
+
+        self.postfix.push(Unary {
+            op: BoxedParser::new(op),
+            bp: binding_power(prec).0,
+        });
+        self
+    }
+}
+
+// the pair of binding powers `prec` sits between
+fn binding_power(prec: u32) -> (u32, u32) {
+    match prec.checked_mul(2).and_then(|lo| lo.checked_add(1)) {
+        Some(hi) => (hi - 1, hi),
+        None => panic!("operator precedence {} is too high", prec),
+    }
+}
+
+impl<'p, I, O> Precedence<'p, I, O>
+where
+    I: Input,
+{
+    fn expr(&self, input: I, min_bp: u32) -> ParseResult<O, I> {
+        let (mut lhs, mut cursor) = self.operand(input)?;
+
+        'outer: loop {
+            for post in &self.postfix {
+                match post.op.parse(cursor) {
+                    Ok((_, xs)) if xs.input_len() == cursor.input_len() => {},
+                    Ok(_) if post.bp < min_bp => break 'outer,
+                    Ok((f, xs)) => {
+                        lhs = f(lhs);
+                        cursor = xs;
+                        continue 'outer;
+                    },
+                    Err(e) if !e.is_recoverable() => return Err(e),
+                    Err(_) => {},
+                }
+            }
+
+            for inf in &self.infix {
+                match inf.op.parse(cursor) {
+                    Ok(_) if inf.lbp < min_bp => break 'outer,
+                    Ok((f, xs)) => {
+                        let (rhs, xs) = self.expr(xs, inf.rbp)?;
+                        if xs.input_len() == cursor.input_len() {
+                            break 'outer;
+                        }
+
+                        lhs = f(lhs, rhs);
+                        cursor = xs;
+                        continue 'outer;
+                    },
+                    Err(e) if !e.is_recoverable() => return Err(e),
+                    Err(_) => {},
+                }
+            }
+
+            break;
+        }
+
+        Ok((lhs, cursor))
+    }
+
+    // an atom, or a prefix operator applied to what follows it
+    fn operand(&self, input: I) -> ParseResult<O, I> {
+        let mut err: Option<ParseError<I>> = None;
+
+        for pre in &self.prefix {
+            match pre.op.parse(input) {
+                Ok((f, xs)) => {
+                    let (x, xs) = self.expr(xs, pre.bp)?;
+                    return Ok((f(x), xs));
+                },
+                Err(e) if !e.is_recoverable() => return Err(e),
+                Err(e) => err = Some(match err {
+                    Some(err) => err.merge(e),
+                    None => e,
+                }),
+            }
+        }
+
+        match self.atom.parse(input) {
+            Ok(res) => Ok(res),
+            Err(e) if !e.is_recoverable() => Err(e),
+            Err(e) => Err(match err {
+                Some(err) => err.merge(e),
+                None => e,
+            }),
+        }
+    }
+}
+
+impl<'p, I, O> Parse<I> for Precedence<'p, I, O>
+where
+    I: Input,
+{
+    type Output = O;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        self.expr(input, 0)
+    }
+}
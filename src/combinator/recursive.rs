@@ -0,0 +1,74 @@
+use std::cell::OnceCell;
+use std::rc::{Rc, Weak};
+
+use crate::parser::{Parse, ParseResult};
+
+type Slot<'p, I, O> = OnceCell<Box<dyn Parse<I, Output = O> + 'p>>;
+
+// how close to the end of the stack we get before switching to a new segment
+const RED_ZONE: usize = 64 * 1024;
+const STACK_SIZE: usize = 1024 * 1024;
+
+/// A parser that can refer to itself, for nested grammars.
+///
+/// Built with [`Recursive::new`], which hands the closure a handle to the
+/// parser being defined. Each level of nesting runs on a fresh stack
+/// segment when the current one runs low, so deep input doesn't overflow.
+pub struct Recursive<'p, I, O> {
+    slot: Handle<'p, I, O>,
+}
+
+enum Handle<'p, I, O> {
+    Owned(Rc<Slot<'p, I, O>>),
+    // the copies inside the definition, which would otherwise form a cycle
+    Weak(Weak<Slot<'p, I, O>>),
+}
+
+impl<'p, I, O> Recursive<'p, I, O> {
+    pub fn new<P, F>(f: F) -> Self
+    where
+        F: FnOnce(Recursive<'p, I, O>) -> P,
+        P: Parse<I, Output = O> + 'p,
+    {
+        let slot = Rc::new(OnceCell::new());
+        let this = Self {
+            slot: Handle::Weak(Rc::downgrade(&slot)),
+        };
+
+        let p: Box<dyn Parse<I, Output = O> + 'p> = Box::new(f(this));
+        if slot.set(p).is_err() {
+            unreachable!("recursive parser defined twice");
+        }
+
+        Self {
+            slot: Handle::Owned(slot),
+        }
+    }
+}
+
+impl<'p, I, O> Clone for Recursive<'p, I, O> {
+    fn clone(&self) -> Self {
+        let slot = match &self.slot {
+            Handle::Owned(rc) => Handle::Owned(Rc::clone(rc)),
+            Handle::Weak(weak) => Handle::Weak(Weak::clone(weak)),
+        };
+
+        Self {
+            slot,
+        }
+    }
+}
+
+impl<'p, I, O> Parse<I> for Recursive<'p, I, O> {
+    type Output = O;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        let slot = match &self.slot {
+            Handle::Owned(rc) => Rc::clone(rc),
+            Handle::Weak(weak) => weak.upgrade().expect("recursive parser used after it was dropped"),
+        };
+        let p = slot.get().expect("recursive parser used while it was being defined");
+
+        stacker::maybe_grow(RED_ZONE, STACK_SIZE, || p.parse(input))
+    }
+}
@@ -0,0 +1,113 @@
+use crate::parser::{Input, Parse, ParseResult};
+
+/// Runs a tuple of parsers in order, returning their outputs as a flat tuple.
+pub struct Sequence<T> {
+    pub(crate) ps: T,
+}
+
+impl<T> Sequence<T> {
+    pub fn new(ps: T) -> Self {
+        Self {
+            ps
+        }
+    }
+}
+
+/// Tries a tuple of parsers in order, returning the first success.
+pub struct Alt<T> {
+    pub(crate) ps: T,
+}
+
+impl<T> Alt<T> {
+    pub fn new(ps: T) -> Self {
+        Self {
+            ps
+        }
+    }
+}
+
+macro_rules! impl_sequence {
+    ($($p:ident $o:ident),+) => {
+        impl<I, $($p),+> Parse<I> for Sequence<($($p,)+)>
+        where
+            $($p: Parse<I>,)+
+        {
+            type Output = ($($p::Output,)+);
+
+            #[allow(non_snake_case)]
+            fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+                let ($($p,)+) = &self.ps;
+                $(let ($o, input) = $p.parse(input)?;)+
+                Ok((($($o,)+), input))
+            }
+        }
+    };
+}
+
+// same error handling as `Or`: stop on incomplete input, otherwise merge
+macro_rules! impl_alt {
+    ($p1:ident $(, $p:ident)*) => {
+        impl<I, O, $p1, $($p),*> Parse<I> for Alt<($p1, $($p,)*)>
+        where
+            I: Input,
+            $p1: Parse<I, Output = O>,
+            $($p: Parse<I, Output = O>,)*
+        {
+            type Output = O;
+
+            #[allow(non_snake_case)]
+            fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+                let ($p1, $($p,)*) = &self.ps;
+
+                #[allow(unused_mut)]
+                let mut err = match $p1.parse(input) {
+                    Ok(res) => return Ok(res),
+                    Err(e) if !e.is_recoverable() => return Err(e),
+                    Err(e) => e,
+                };
+                $(
+                    match $p.parse(input) {
+                        Ok(res) => return Ok(res),
+                        Err(e) if !e.is_recoverable() => return Err(e),
+                        Err(e) => err = err.merge(e),
+                    }
+                )*
+                Err(err)
+            }
+        }
+    };
+}
+
+impl_sequence!(P1 o1);
+impl_sequence!(P1 o1, P2 o2);
+impl_sequence!(P1 o1, P2 o2, P3 o3);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7, P8 o8);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7, P8 o8, P9 o9);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7, P8 o8, P9 o9, P10 o10);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7, P8 o8, P9 o9, P10 o10, P11 o11);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7, P8 o8, P9 o9, P10 o10, P11 o11, P12 o12);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7, P8 o8, P9 o9, P10 o10, P11 o11, P12 o12, P13 o13);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7, P8 o8, P9 o9, P10 o10, P11 o11, P12 o12, P13 o13, P14 o14);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7, P8 o8, P9 o9, P10 o10, P11 o11, P12 o12, P13 o13, P14 o14, P15 o15);
+impl_sequence!(P1 o1, P2 o2, P3 o3, P4 o4, P5 o5, P6 o6, P7 o7, P8 o8, P9 o9, P10 o10, P11 o11, P12 o12, P13 o13, P14 o14, P15 o15, P16 o16);
+
+impl_alt!(P1);
+impl_alt!(P1, P2);
+impl_alt!(P1, P2, P3);
+impl_alt!(P1, P2, P3, P4);
+impl_alt!(P1, P2, P3, P4, P5);
+impl_alt!(P1, P2, P3, P4, P5, P6);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7, P8);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7, P8, P9);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7, P8, P9, P10);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13, P14);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13, P14, P15);
+impl_alt!(P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13, P14, P15, P16);
@@ -0,0 +1,311 @@
+mod report;
+
+pub use report::{Position, Report};
+
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::fmt;
+use std::error::Error;
+
+use crate::parser::{Input, Partial};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The input ended before the parser could match.
+    EOF,
+    /// Not enough data to decide whether the parser matches.
+    Indeterminate,
+    /// The input does not match.
+    Invalid,
+}
+
+/// Something a parser was looking for when it failed.
+///
+/// Cheap to clone, so matchers build theirs once and hand out copies
+/// rather than allocating on every failure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    /// An exact sequence such as `":"` or `"\r\n"`.
+    Literal(Arc<str>),
+    /// Any one of a set of sequences, as matched by `OneOf`.
+    OneOf(Arc<[String]>),
+    /// A class of input described in words, such as `digit`.
+    Label(Cow<'static, str>),
+    /// A class of input described at runtime, such as `[0-9a-f]`.
+    Pattern(Arc<str>),
+}
+
+impl Expected {
+    pub fn literal(s: impl Into<Arc<str>>) -> Self {
+        Self::Literal(s.into())
+    }
+
+    pub fn label(s: impl Into<Cow<'static, str>>) -> Self {
+        Self::Label(s.into())
+    }
+
+    pub fn pattern(s: impl Into<Arc<str>>) -> Self {
+        Self::Pattern(s.into())
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(s) => write_literal(f, s),
+            Self::OneOf(xs) => {
+                write!(f, "one of ")?;
+                for (i, s) in xs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write_literal(f, s)?;
+                }
+                Ok(())
+            },
+            Self::Label(s) => write!(f, "{}", s),
+            Self::Pattern(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+fn write_literal(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => write!(f, "'{}'", ch.escape_debug()),
+        _ => write!(f, "\"{}\"", s.escape_debug()),
+    }
+}
+
+/// A parse failure.
+///
+/// `input` is the remainder of the input at the point of failure, so the
+/// position can be recovered against the original input with
+/// [`offset`](ParseError::offset).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError<I> {
+    pub(crate) kind: ErrorKind,
+    pub(crate) input: I,
+    pub(crate) expected: Vec<Expected>,
+    pub(crate) context: Vec<&'static str>,
+    pub(crate) needed: Option<usize>,
+    pub(crate) fatal: bool,
+}
+
+impl<I> ParseError<I> {
+    pub fn new(kind: ErrorKind, input: I) -> Self {
+        Self {
+            kind,
+            input,
+            expected: Vec::new(),
+            context: Vec::new(),
+            needed: None,
+            fatal: false,
+        }
+    }
+
+    pub fn invalid(input: I, expected: Expected) -> Self {
+        Self::new(ErrorKind::Invalid, input).expect(expected)
+    }
+
+    pub fn eof(input: I, expected: Expected) -> Self {
+        Self::new(ErrorKind::EOF, input).expect(expected)
+    }
+
+    /// Adds `expected` to the set of alternatives.
+    pub fn expect(mut self, expected: Expected) -> Self {
+        if !self.expected.contains(&expected) {
+            self.expected.push(expected);
+        }
+        self
+    }
+
+    /// Records that at least `n` more units of input are needed to match.
+    pub fn need(mut self, n: usize) -> Self {
+        self.needed = Some(n);
+        self
+    }
+
+    /// Records that the failure happened inside `label`.
+    ///
+    /// Labels are added from the innermost parser outwards.
+    pub fn within(mut self, label: &'static str) -> Self {
+        self.context.insert(0, label);
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Whether the parser stopped because it needs more data.
+    pub fn is_incomplete(&self) -> bool {
+        self.kind == ErrorKind::Indeterminate
+    }
+
+    /// Marks the error as fatal, see [`is_recoverable`](ParseError::is_recoverable).
+    pub fn cut(mut self) -> Self {
+        self.fatal = true;
+        self
+    }
+
+    /// Whether the error was raised after a [`cut`](crate::combinator::Cut).
+    pub fn is_fatal(&self) -> bool {
+        self.fatal
+    }
+
+    /// Whether `Or`, `Optional` and the repeating combinators may try
+    /// something else after this error.
+    ///
+    /// They can't when more data is needed or after a cut.
+    pub fn is_recoverable(&self) -> bool {
+        !self.is_incomplete() && !self.fatal
+    }
+
+    /// How many more units of input are needed, if known.
+    pub fn needed(&self) -> Option<usize> {
+        self.needed
+    }
+
+    pub fn input(&self) -> &I {
+        &self.input
+    }
+
+    pub fn expected(&self) -> &[Expected] {
+        &self.expected
+    }
+
+    /// The labels the failure happened inside, outermost first.
+    pub fn context(&self) -> &[&'static str] {
+        &self.context
+    }
+}
+
+impl<I> ParseError<I>
+where
+    I: Input,
+{
+    /// Fails because the input ended: `EOF` for complete input and
+    /// `Indeterminate` for [`Partial`](crate::parser::Partial) input.
+    pub fn end(input: I) -> Self {
+        match input.is_partial() {
+            true => Self::new(ErrorKind::Indeterminate, input),
+            false => Self::new(ErrorKind::EOF, input),
+        }
+    }
+
+    /// Offset of the failure from the start of `original`, in the input's
+    /// units (bytes for `&str`).
+    pub fn offset(&self, original: I) -> usize {
+        original.input_len() - self.input.input_len()
+    }
+
+    /// Combines the errors of two alternatives that started at the same
+    /// position.
+    ///
+    /// The error that got further wins; if both stopped at the same
+    /// position their expected sets are unioned and only the context
+    /// labels they share are kept.
+    pub fn merge(self, other: Self) -> Self {
+        let (a, b) = (self.input.input_len(), other.input.input_len());
+
+        if b < a {
+            return other;
+        }
+        if a < b {
+            return self;
+        }
+
+        let mut merged = self;
+        merged.fatal |= other.fatal;
+        if other.kind == ErrorKind::Invalid {
+            merged.kind = ErrorKind::Invalid;
+        }
+        merged.needed = match (merged.needed, other.needed) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        let shared = merged.context.iter().zip(&other.context).take_while(|(a, b)| a == b).count();
+        merged.context.truncate(shared);
+        for expected in other.expected {
+            merged = merged.expect(expected);
+        }
+        merged
+    }
+}
+
+impl<I> ParseError<Partial<I>> {
+    pub fn into_inner(self) -> ParseError<I> {
+        ParseError {
+            kind: self.kind,
+            input: self.input.0,
+            expected: self.expected,
+            context: self.context,
+            needed: self.needed,
+            fatal: self.fatal,
+        }
+    }
+}
+
+impl<I> ParseError<I> {
+    /// The error for the same failure on [`Partial`] input, where running
+    /// out of data is no longer final.
+    pub fn into_partial(self) -> ParseError<Partial<I>> {
+        let kind = match self.kind {
+            ErrorKind::EOF => ErrorKind::Indeterminate,
+            kind => kind,
+        };
+
+        ParseError {
+            kind,
+            input: Partial(self.input),
+            expected: self.expected,
+            context: self.context,
+            needed: self.needed,
+            fatal: self.fatal,
+        }
+    }
+}
+
+impl<I> fmt::Display for ParseError<I>
+where
+    I: Input,
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for label in &self.context {
+            write!(f, "in {} > ", label)?;
+        }
+
+        match self.expected.len() {
+            0 => write!(f, "match failed")?,
+            n => {
+                write!(f, "expected ")?;
+                for (i, expected) in self.expected.iter().enumerate() {
+                    if i > 0 && i == n - 1 {
+                        write!(f, " or ")?;
+                    } else if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", expected)?;
+                }
+            },
+        }
+
+        match (self.kind, self.input.next_item()) {
+            (ErrorKind::Indeterminate, _) => match self.needed {
+                Some(n) => write!(f, ", need {} more", n),
+                None => write!(f, ", need more data"),
+            },
+            (_, Some((item, _))) => write!(f, ", found {:?}", item),
+            (_, None) => write!(f, ", found end of input"),
+        }
+    }
+}
+
+impl<I> Error for ParseError<I>
+where
+    I: Input + fmt::Debug,
+    I::Item: fmt::Debug,
+{
+}
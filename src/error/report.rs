@@ -0,0 +1,93 @@
+use std::fmt;
+
+use crate::error::ParseError;
+
+/// A 1-based line and column, with columns counted in chars.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub fn from_offset(source: &str, offset: usize) -> Self {
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// Renders a [`ParseError`] against the source it came from.
+///
+/// ```text
+/// error: expected ':', found ' '
+///  --> 3:5
+///   |
+/// 3 | Host www.example.com
+///   |     ^
+/// ```
+pub struct Report<'a, 'e> {
+    source: &'a str,
+    error: &'e ParseError<&'a str>,
+}
+
+impl<'a, 'e> Report<'a, 'e> {
+    pub fn new(source: &'a str, error: &'e ParseError<&'a str>) -> Self {
+        Self {
+            source,
+            error,
+        }
+    }
+
+    pub fn position(&self) -> Position {
+        Position::from_offset(self.source, self.error.offset(self.source))
+    }
+
+    // the source line containing `offset`, without its line ending
+    fn line(&self, offset: usize) -> &'a str {
+        let start = self.source[..offset].rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        let end = self.source[offset..].find('\n').map(|idx| offset + idx).unwrap_or(self.source.len());
+        self.source[start..end].trim_end_matches('\r')
+    }
+}
+
+impl fmt::Display for Report<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let offset = self.error.offset(self.source);
+        let pos = self.position();
+        let line = self.line(offset);
+        let gutter = pos.line.to_string().len();
+
+        // keep tabs so the caret lines up with the source line
+        let pad: String = line.chars()
+            .take(pos.column - 1)
+            .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        writeln!(f, "error: {}", self.error)?;
+        writeln!(f, "{:w$}--> {}", "", pos, w = gutter)?;
+        writeln!(f, "{:w$} |", "", w = gutter)?;
+        writeln!(f, "{} | {}", pos.line, line)?;
+        writeln!(f, "{:w$} | {}^", "", pad, w = gutter)
+    }
+}
+
+impl<'a> ParseError<&'a str> {
+    pub fn position(&self, source: &'a str) -> Position {
+        Position::from_offset(source, self.offset(source))
+    }
+
+    pub fn report<'e>(&'e self, source: &'a str) -> Report<'a, 'e> {
+        Report::new(source, self)
+    }
+}
@@ -1,5 +1,6 @@
 pub mod byte_parser;
 pub mod str_parser;
+pub mod token_parser;
 
 #[cfg(test)]
 mod testing;
@@ -10,9 +11,12 @@ pub mod matcher;
 pub mod parser;
 
 pub mod str {
+    pub use crate::parser::Parse;
     pub use crate::str_parser::ParseStr;
     use crate::combinator::*;
     use crate::matcher::*;
+    use crate::parser::ParseIter;
+    use std::ops::RangeBounds;
 
     pub fn many0<'a, P>(p: P) -> Many0<P>
     where
@@ -28,6 +32,193 @@ pub mod str {
         Many1::new(p)
     }
 
+    pub fn parse_iter<'p, 'a, P>(p: &'p P, input: &'a str) -> ParseIter<'p, P, &'a str>
+    where
+        P: ParseStr<'a>,
+    {
+        ParseIter::new(p, input)
+    }
+
+    pub fn fold_many0<'a, P, Init, F, B>(p: P, init: Init, f: F) -> FoldMany0<P, Init, F>
+    where
+        P: ParseStr<'a>,
+        Init: Fn() -> B,
+        F: Fn(B, P::Output) -> B,
+    {
+        FoldMany0::new(p, init, f)
+    }
+
+    pub fn fold_many1<'a, P, Init, F, B>(p: P, init: Init, f: F) -> FoldMany1<P, Init, F>
+    where
+        P: ParseStr<'a>,
+        Init: Fn() -> B,
+        F: Fn(B, P::Output) -> B,
+    {
+        FoldMany1::new(p, init, f)
+    }
+
+    pub fn many0_count<'a, P>(p: P) -> Many0Count<P>
+    where
+        P: ParseStr<'a>,
+    {
+        Many0Count::new(p)
+    }
+
+    pub fn many_m_n<'a, P, R>(p: P, range: R) -> ManyMN<P>
+    where
+        P: ParseStr<'a>,
+        R: RangeBounds<usize>,
+    {
+        ManyMN::new(p, range)
+    }
+
+    pub fn many_till<'a, P, E>(p: P, end: E) -> ManyTill<P, E>
+    where
+        P: ParseStr<'a>,
+        E: ParseStr<'a>,
+    {
+        ManyTill::new(p, end)
+    }
+
+    pub fn sep_by<'a, P, S>(p: P, sep: S) -> SepBy<P, S>
+    where
+        P: ParseStr<'a>,
+        S: ParseStr<'a>,
+    {
+        SepBy::new(p, sep)
+    }
+
+    pub fn sep_by1<'a, P, S>(p: P, sep: S) -> SepBy1<P, S>
+    where
+        P: ParseStr<'a>,
+        S: ParseStr<'a>,
+    {
+        SepBy1::new(p, sep)
+    }
+
+    pub fn sep_end_by<'a, P, S>(p: P, sep: S) -> SepEndBy<P, S>
+    where
+        P: ParseStr<'a>,
+        S: ParseStr<'a>,
+    {
+        SepEndBy::new(p, sep)
+    }
+
+    pub fn end_by<'a, P, S>(p: P, sep: S) -> EndBy<P, S>
+    where
+        P: ParseStr<'a>,
+        S: ParseStr<'a>,
+    {
+        EndBy::new(p, sep)
+    }
+
+    pub fn recursive<'p, 'a, O, P, F>(f: F) -> Recursive<'p, &'a str, O>
+    where
+        F: FnOnce(Recursive<'p, &'a str, O>) -> P,
+        P: ParseStr<'a, Output = O> + 'p,
+    {
+        Recursive::new(f)
+    }
+
+    pub fn cut<'a, P>(p: P) -> Cut<P>
+    where
+        P: ParseStr<'a>,
+    {
+        Cut::new(p)
+    }
+
+    pub fn peek<'a, P>(p: P) -> Peek<P>
+    where
+        P: ParseStr<'a>,
+    {
+        Peek::new(p)
+    }
+
+    pub fn not<'a, P>(p: P) -> Not<P>
+    where
+        P: ParseStr<'a>,
+    {
+        Not::new(p)
+    }
+
+    pub fn eof() -> Eof {
+        Eof::new()
+    }
+
+    pub fn preceded<'a, P1, P2>(p1: P1, p2: P2) -> Skip<Left, P1, P2>
+    where
+        P1: ParseStr<'a>,
+        P2: ParseStr<'a>,
+    {
+        Skip::<Left, _, _>::new(p1, p2)
+    }
+
+    pub fn terminated<'a, P1, P2>(p1: P1, p2: P2) -> Skip<Right, P1, P2>
+    where
+        P1: ParseStr<'a>,
+        P2: ParseStr<'a>,
+    {
+        Skip::<Right, _, _>::new(p1, p2)
+    }
+
+    pub fn delimited<'a, O, P, C>(open: O, p: P, close: C) -> Delimited<O, P, C>
+    where
+        O: ParseStr<'a>,
+        P: ParseStr<'a>,
+        C: ParseStr<'a>,
+    {
+        Delimited::new(open, p, close)
+    }
+
+    pub fn seq<T>(ps: T) -> Sequence<T> {
+        Sequence::new(ps)
+    }
+
+    pub fn alt<T>(ps: T) -> Alt<T> {
+        Alt::new(ps)
+    }
+
+    pub fn chainl1<'a, P, Op, F>(p: P, op: Op) -> ChainL1<P, Op>
+    where
+        P: ParseStr<'a>,
+        Op: ParseStr<'a, Output = F>,
+        F: Fn(P::Output, P::Output) -> P::Output,
+    {
+        ChainL1::new(p, op)
+    }
+
+    pub fn chainr1<'a, P, Op, F>(p: P, op: Op) -> ChainR1<P, Op>
+    where
+        P: ParseStr<'a>,
+        Op: ParseStr<'a, Output = F>,
+        F: Fn(P::Output, P::Output) -> P::Output,
+    {
+        ChainR1::new(p, op)
+    }
+
+    pub fn precedence<'p, 'a, O, P>(atom: P) -> Precedence<'p, &'a str, O>
+    where
+        P: ParseStr<'a, Output = O> + 'p,
+    {
+        Precedence::new(atom)
+    }
+
+    pub fn choice<'a, P>(ps: Vec<P>) -> Choice<P>
+    where
+        P: ParseStr<'a>,
+    {
+        Choice::new(ps)
+    }
+
+    pub fn separated_pair<'a, P1, S, P2>(p1: P1, sep: S, p2: P2) -> SeparatedPair<P1, S, P2>
+    where
+        P1: ParseStr<'a>,
+        S: ParseStr<'a>,
+        P2: ParseStr<'a>,
+    {
+        SeparatedPair::new(p1, sep, p2)
+    }
+
     pub fn one_byte(byte: u8) -> One<u8> {
         One::<u8>::new(byte)
     }
@@ -37,10 +228,29 @@ pub mod str {
         str_seq(&s)
     }
 
+    pub fn one_of(chars: &str) -> CharClass {
+        CharClass::new().chars(chars)
+    }
+
+    pub fn satisfy<F>(f: F) -> Satisfy<F>
+    where
+        F: Fn(char) -> bool,
+    {
+        Satisfy::new(f)
+    }
+
     pub fn str_seq(s: &str) -> Seq<u8> {
         Seq::<char>::new(s)
     }
 
+    pub fn str_seq_nocase(s: &str) -> NoCase<Seq<u8>> {
+        NoCase::new(Seq::<char>::new(s))
+    }
+
+    pub fn one_of_nocase(xs: &[&str]) -> NoCase<OneOf<String>> {
+        NoCase::new(OneOf::<String>::from(xs))
+    }
+
     pub fn take_until<'a, P>(p: P) -> TakeUntil<P>
     where
         P: ParseStr<'a>,
@@ -48,18 +258,541 @@ pub mod str {
         TakeUntil::new(p)
     }
 
-    pub fn take_while<'a, F>(f: F) -> TakeWhile<F>
+    pub fn take_through<'a, P>(p: P) -> TakeThrough<P>
+    where
+        P: ParseStr<'a>,
+    {
+        TakeThrough::new(p)
+    }
+
+    pub fn take_while<F>(f: F) -> TakeWhile<F>
+    where
+        F: Fn(char) -> bool,
+    {
+        TakeWhile::new(f)
+    }
+
+    /// Like `take_while`, but searches a byte at a time for ASCII classes.
+    pub fn take_while_in(class: CharClass) -> TakeWhile<CharClass> {
+        TakeWhile::new(class)
+    }
+}
+
+pub mod bytes {
+    pub use crate::parser::Parse;
+    pub use crate::byte_parser::ParseByte;
+    use crate::combinator::*;
+    use crate::matcher::*;
+    use crate::parser::ParseIter;
+    use std::ops::RangeBounds;
+
+    pub fn many0<'a, P>(p: P) -> Many0<P>
+    where
+        P: ParseByte<'a>,
+    {
+        Many0::new(p)
+    }
+
+    pub fn many1<'a, P>(p: P) -> Many1<P>
+    where
+        P: ParseByte<'a>,
+    {
+        Many1::new(p)
+    }
+
+    pub fn parse_iter<'p, 'a, P>(p: &'p P, input: &'a [u8]) -> ParseIter<'p, P, &'a [u8]>
+    where
+        P: ParseByte<'a>,
+    {
+        ParseIter::new(p, input)
+    }
+
+    pub fn fold_many0<'a, P, Init, F, B>(p: P, init: Init, f: F) -> FoldMany0<P, Init, F>
+    where
+        P: ParseByte<'a>,
+        Init: Fn() -> B,
+        F: Fn(B, P::Output) -> B,
+    {
+        FoldMany0::new(p, init, f)
+    }
+
+    pub fn fold_many1<'a, P, Init, F, B>(p: P, init: Init, f: F) -> FoldMany1<P, Init, F>
+    where
+        P: ParseByte<'a>,
+        Init: Fn() -> B,
+        F: Fn(B, P::Output) -> B,
+    {
+        FoldMany1::new(p, init, f)
+    }
+
+    pub fn many0_count<'a, P>(p: P) -> Many0Count<P>
+    where
+        P: ParseByte<'a>,
+    {
+        Many0Count::new(p)
+    }
+
+    pub fn many_m_n<'a, P, R>(p: P, range: R) -> ManyMN<P>
+    where
+        P: ParseByte<'a>,
+        R: RangeBounds<usize>,
+    {
+        ManyMN::new(p, range)
+    }
+
+    pub fn many_till<'a, P, E>(p: P, end: E) -> ManyTill<P, E>
+    where
+        P: ParseByte<'a>,
+        E: ParseByte<'a>,
+    {
+        ManyTill::new(p, end)
+    }
+
+    pub fn sep_by<'a, P, S>(p: P, sep: S) -> SepBy<P, S>
+    where
+        P: ParseByte<'a>,
+        S: ParseByte<'a>,
+    {
+        SepBy::new(p, sep)
+    }
+
+    pub fn sep_by1<'a, P, S>(p: P, sep: S) -> SepBy1<P, S>
+    where
+        P: ParseByte<'a>,
+        S: ParseByte<'a>,
+    {
+        SepBy1::new(p, sep)
+    }
+
+    pub fn sep_end_by<'a, P, S>(p: P, sep: S) -> SepEndBy<P, S>
+    where
+        P: ParseByte<'a>,
+        S: ParseByte<'a>,
+    {
+        SepEndBy::new(p, sep)
+    }
+
+    pub fn end_by<'a, P, S>(p: P, sep: S) -> EndBy<P, S>
+    where
+        P: ParseByte<'a>,
+        S: ParseByte<'a>,
+    {
+        EndBy::new(p, sep)
+    }
+
+    pub fn recursive<'p, 'a, O, P, F>(f: F) -> Recursive<'p, &'a [u8], O>
+    where
+        F: FnOnce(Recursive<'p, &'a [u8], O>) -> P,
+        P: ParseByte<'a, Output = O> + 'p,
+    {
+        Recursive::new(f)
+    }
+
+    pub fn cut<'a, P>(p: P) -> Cut<P>
+    where
+        P: ParseByte<'a>,
+    {
+        Cut::new(p)
+    }
+
+    pub fn peek<'a, P>(p: P) -> Peek<P>
+    where
+        P: ParseByte<'a>,
+    {
+        Peek::new(p)
+    }
+
+    pub fn not<'a, P>(p: P) -> Not<P>
+    where
+        P: ParseByte<'a>,
+    {
+        Not::new(p)
+    }
+
+    pub fn eof() -> Eof {
+        Eof::new()
+    }
+
+    pub fn preceded<'a, P1, P2>(p1: P1, p2: P2) -> Skip<Left, P1, P2>
+    where
+        P1: ParseByte<'a>,
+        P2: ParseByte<'a>,
+    {
+        Skip::<Left, _, _>::new(p1, p2)
+    }
+
+    pub fn terminated<'a, P1, P2>(p1: P1, p2: P2) -> Skip<Right, P1, P2>
+    where
+        P1: ParseByte<'a>,
+        P2: ParseByte<'a>,
+    {
+        Skip::<Right, _, _>::new(p1, p2)
+    }
+
+    pub fn delimited<'a, O, P, C>(open: O, p: P, close: C) -> Delimited<O, P, C>
+    where
+        O: ParseByte<'a>,
+        P: ParseByte<'a>,
+        C: ParseByte<'a>,
+    {
+        Delimited::new(open, p, close)
+    }
+
+    pub fn seq<T>(ps: T) -> Sequence<T> {
+        Sequence::new(ps)
+    }
+
+    pub fn alt<T>(ps: T) -> Alt<T> {
+        Alt::new(ps)
+    }
+
+    pub fn chainl1<'a, P, Op, F>(p: P, op: Op) -> ChainL1<P, Op>
+    where
+        P: ParseByte<'a>,
+        Op: ParseByte<'a, Output = F>,
+        F: Fn(P::Output, P::Output) -> P::Output,
+    {
+        ChainL1::new(p, op)
+    }
+
+    pub fn chainr1<'a, P, Op, F>(p: P, op: Op) -> ChainR1<P, Op>
+    where
+        P: ParseByte<'a>,
+        Op: ParseByte<'a, Output = F>,
+        F: Fn(P::Output, P::Output) -> P::Output,
+    {
+        ChainR1::new(p, op)
+    }
+
+    pub fn precedence<'p, 'a, O, P>(atom: P) -> Precedence<'p, &'a [u8], O>
+    where
+        P: ParseByte<'a, Output = O> + 'p,
+    {
+        Precedence::new(atom)
+    }
+
+    pub fn choice<'a, P>(ps: Vec<P>) -> Choice<P>
+    where
+        P: ParseByte<'a>,
+    {
+        Choice::new(ps)
+    }
+
+    pub fn separated_pair<'a, P1, S, P2>(p1: P1, sep: S, p2: P2) -> SeparatedPair<P1, S, P2>
+    where
+        P1: ParseByte<'a>,
+        S: ParseByte<'a>,
+        P2: ParseByte<'a>,
+    {
+        SeparatedPair::new(p1, sep, p2)
+    }
+
+    pub fn one_byte(byte: u8) -> One<u8> {
+        One::<u8>::new(byte)
+    }
+
+    pub fn satisfy<F>(f: F) -> Satisfy<F>
+    where
+        F: Fn(&u8) -> bool,
+    {
+        Satisfy::new(f)
+    }
+
+    pub fn byte_seq(s: &[u8]) -> Seq<u8> {
+        Seq::<u8>::new(s)
+    }
+
+    pub fn byte_seq_nocase(s: &[u8]) -> NoCase<Seq<u8>> {
+        NoCase::ascii(Seq::<u8>::new(s))
+    }
+
+    pub fn one_of_nocase(xs: &[&str]) -> NoCase<OneOf<String>> {
+        NoCase::ascii(OneOf::<String>::from(xs))
+    }
+
+    pub fn take_until<'a, P>(p: P) -> TakeUntil<P>
+    where
+        P: ParseByte<'a>,
+    {
+        TakeUntil::new(p)
+    }
+
+    pub fn take_through<'a, P>(p: P) -> TakeThrough<P>
+    where
+        P: ParseByte<'a>,
+    {
+        TakeThrough::new(p)
+    }
+
+    pub fn take_while<F>(f: F) -> TakeWhile<F>
+    where
+        F: Fn(&u8) -> bool,
+    {
+        TakeWhile::new(f)
+    }
+
+    pub fn take_while_in(class: CharClass) -> TakeWhile<CharClass> {
+        TakeWhile::new(class)
+    }
+}
+
+pub mod tokens {
+    pub use crate::parser::Parse;
+    pub use crate::token_parser::ParseTokens;
+    use crate::combinator::*;
+    use crate::matcher::*;
+    use std::ops::RangeBounds;
+
+    pub fn many0<'a, T, P>(p: P) -> Many0<P>
+    where
+        P: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Many0::new(p)
+    }
+
+    pub fn many1<'a, T, P>(p: P) -> Many1<P>
+    where
+        P: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Many1::new(p)
+    }
+
+    pub fn fold_many0<'a, T, P, Init, F, B>(p: P, init: Init, f: F) -> FoldMany0<P, Init, F>
+    where
+        P: Parse<&'a [T]>,
+        Init: Fn() -> B,
+        F: Fn(B, P::Output) -> B,
+        T: 'a,
+    {
+        FoldMany0::new(p, init, f)
+    }
+
+    pub fn fold_many1<'a, T, P, Init, F, B>(p: P, init: Init, f: F) -> FoldMany1<P, Init, F>
+    where
+        P: Parse<&'a [T]>,
+        Init: Fn() -> B,
+        F: Fn(B, P::Output) -> B,
+        T: 'a,
+    {
+        FoldMany1::new(p, init, f)
+    }
+
+    pub fn many0_count<'a, T, P>(p: P) -> Many0Count<P>
+    where
+        P: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Many0Count::new(p)
+    }
+
+    pub fn many_m_n<'a, T, P, R>(p: P, range: R) -> ManyMN<P>
+    where
+        P: Parse<&'a [T]>,
+        R: RangeBounds<usize>,
+        T: 'a,
+    {
+        ManyMN::new(p, range)
+    }
+
+    pub fn many_till<'a, T, P, E>(p: P, end: E) -> ManyTill<P, E>
+    where
+        P: Parse<&'a [T]>,
+        E: Parse<&'a [T]>,
+        T: 'a,
+    {
+        ManyTill::new(p, end)
+    }
+
+    pub fn sep_by<'a, T, P, S>(p: P, sep: S) -> SepBy<P, S>
+    where
+        P: Parse<&'a [T]>,
+        S: Parse<&'a [T]>,
+        T: 'a,
+    {
+        SepBy::new(p, sep)
+    }
+
+    pub fn sep_by1<'a, T, P, S>(p: P, sep: S) -> SepBy1<P, S>
+    where
+        P: Parse<&'a [T]>,
+        S: Parse<&'a [T]>,
+        T: 'a,
+    {
+        SepBy1::new(p, sep)
+    }
+
+    pub fn sep_end_by<'a, T, P, S>(p: P, sep: S) -> SepEndBy<P, S>
+    where
+        P: Parse<&'a [T]>,
+        S: Parse<&'a [T]>,
+        T: 'a,
+    {
+        SepEndBy::new(p, sep)
+    }
+
+    pub fn end_by<'a, T, P, S>(p: P, sep: S) -> EndBy<P, S>
+    where
+        P: Parse<&'a [T]>,
+        S: Parse<&'a [T]>,
+        T: 'a,
+    {
+        EndBy::new(p, sep)
+    }
+
+    pub fn seq<T>(ps: T) -> Sequence<T> {
+        Sequence::new(ps)
+    }
+
+    pub fn alt<T>(ps: T) -> Alt<T> {
+        Alt::new(ps)
+    }
+
+    pub fn chainl1<'a, T, P, Op, F>(p: P, op: Op) -> ChainL1<P, Op>
+    where
+        P: Parse<&'a [T]>,
+        Op: Parse<&'a [T], Output = F>,
+        F: Fn(P::Output, P::Output) -> P::Output,
+        T: 'a,
+    {
+        ChainL1::new(p, op)
+    }
+
+    pub fn chainr1<'a, T, P, Op, F>(p: P, op: Op) -> ChainR1<P, Op>
+    where
+        P: Parse<&'a [T]>,
+        Op: Parse<&'a [T], Output = F>,
+        F: Fn(P::Output, P::Output) -> P::Output,
+        T: 'a,
+    {
+        ChainR1::new(p, op)
+    }
+
+    pub fn precedence<'p, 'a, T, O, P>(atom: P) -> Precedence<'p, &'a [T], O>
+    where
+        P: Parse<&'a [T], Output = O> + 'p,
+        T: 'a,
+    {
+        Precedence::new(atom)
+    }
+
+    pub fn choice<'a, T, P>(ps: Vec<P>) -> Choice<P>
+    where
+        P: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Choice::new(ps)
+    }
+
+    pub fn recursive<'p, 'a, T, O, P, F>(f: F) -> Recursive<'p, &'a [T], O>
+    where
+        F: FnOnce(Recursive<'p, &'a [T], O>) -> P,
+        P: Parse<&'a [T], Output = O> + 'p,
+        T: 'a,
+    {
+        Recursive::new(f)
+    }
+
+    pub fn cut<'a, T, P>(p: P) -> Cut<P>
+    where
+        P: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Cut::new(p)
+    }
+
+    pub fn peek<'a, T, P>(p: P) -> Peek<P>
+    where
+        P: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Peek::new(p)
+    }
+
+    pub fn not<'a, T, P>(p: P) -> Not<P>
+    where
+        P: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Not::new(p)
+    }
+
+    pub fn eof() -> Eof {
+        Eof::new()
+    }
+
+    pub fn preceded<'a, T, P1, P2>(p1: P1, p2: P2) -> Skip<Left, P1, P2>
+    where
+        P1: Parse<&'a [T]>,
+        P2: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Skip::<Left, _, _>::new(p1, p2)
+    }
+
+    pub fn terminated<'a, T, P1, P2>(p1: P1, p2: P2) -> Skip<Right, P1, P2>
+    where
+        P1: Parse<&'a [T]>,
+        P2: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Skip::<Right, _, _>::new(p1, p2)
+    }
+
+    pub fn delimited<'a, T, O, P, C>(open: O, p: P, close: C) -> Delimited<O, P, C>
+    where
+        O: Parse<&'a [T]>,
+        P: Parse<&'a [T]>,
+        C: Parse<&'a [T]>,
+        T: 'a,
+    {
+        Delimited::new(open, p, close)
+    }
+
+    pub fn separated_pair<'a, T, P1, S, P2>(p1: P1, sep: S, p2: P2) -> SeparatedPair<P1, S, P2>
+    where
+        P1: Parse<&'a [T]>,
+        S: Parse<&'a [T]>,
+        P2: Parse<&'a [T]>,
+        T: 'a,
+    {
+        SeparatedPair::new(p1, sep, p2)
+    }
+
+    pub fn token<T: Token>(x: T) -> One<T> {
+        One::token(x)
+    }
+
+    pub fn token_seq<T: Token + Clone>(xs: &[T]) -> Seq<T> {
+        Seq::tokens(xs)
+    }
+
+    pub fn one_of<T: Token + Clone>(xs: &[T]) -> OneOf<T> {
+        OneOf::tokens(xs)
+    }
+
+    pub fn take_until<'a, T, P>(p: P) -> TakeUntil<P>
+    where
+        P: Parse<&'a [T]>,
+        T: 'a,
+    {
+        TakeUntil::new(p)
+    }
+
+    pub fn take_while<T, F>(f: F) -> TakeWhile<F>
     where
-        F: Fn(&'a str) -> bool,
+        F: Fn(&T) -> bool,
     {
         TakeWhile::new(f)
     }
 }
 
 pub mod prelude {
-    pub use super::error::ParseError;
-    pub use super::combinator::{And, Many0, Many1, ManyN, Map, Optional, Or, Skip, State, TakeUntil, TakeWhile};
-    pub use super::matcher::{Any, Digit, Letter, One, OneOf, Seq, Whitespace};
+    pub use super::error::{ErrorKind, Expected, ParseError};
+    pub use super::parser::{Input, Parse, ParseIter, ParseResult, Partial};
+    pub use super::combinator::{Alt, And, Assoc, BoxedParser, BoxedSliceParser, BoxedStrParser, ChainL1, ChainR1, Choice, Collect, Context, Cut, Delimited, EndBy, FoldMany0, FoldMany1, Many0, Many0Count, Many1, ManyMN, ManyN, ManyTill, Map, Not, Optional, Or, Peek, Precedence, Recursive, SepBy, SepBy1, SepEndBy, SeparatedPair, Sequence, Skip, State, TakeThrough, TakeUntil, TakeWhile};
+    pub use super::matcher::{Any, Category, CharClass, Digit, Eof, Fold, Letter, NoCase, One, OneOf, Satisfy, Seq, Token, Whitespace};
     pub use super::util::whitespace;
 }
 
@@ -0,0 +1,259 @@
+use std::fmt::{self, Write};
+use std::ops::RangeInclusive;
+use std::sync::OnceLock;
+
+use crate::error::Expected;
+use crate::matcher::{Category, Token};
+
+/// A set of chars, built like a regex bracket expression.
+///
+/// ASCII members live in a bitmap; everything above is kept as a sorted
+/// table of disjoint ranges and looked up with a binary search.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CharClass {
+    pub(crate) ascii: [u64; 2],
+    pub(crate) ranges: Vec<(char, char)>,
+    pub(crate) categories: Vec<Category>,
+    // classes that can't be merged into this one, such as negated ones
+    pub(crate) alts: Vec<CharClass>,
+    pub(crate) negated: bool,
+    expected: ExpectedCell,
+}
+
+// the class's `Expected`, built on its first failure
+#[derive(Clone, Default)]
+struct ExpectedCell(OnceLock<Expected>);
+
+// a cache, so two classes with the same members are still equal
+impl PartialEq for ExpectedCell {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for ExpectedCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("..")
+    }
+}
+
+impl CharClass {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn digit() -> Self {
+        Self::new().range('0'..='9')
+    }
+
+    pub fn hex_digit() -> Self {
+        Self::new().range('0'..='9').range('a'..='f').range('A'..='F')
+    }
+
+    pub fn alpha() -> Self {
+        Self::new().range('a'..='z').range('A'..='Z')
+    }
+
+    pub fn alphanumeric() -> Self {
+        Self::alpha().range('0'..='9')
+    }
+
+    pub fn space() -> Self {
+        Self::new().chars(" \t\r\n\x0b\x0c")
+    }
+
+    pub fn char(self, ch: char) -> Self {
+        self.range(ch..=ch)
+    }
+
+    pub fn chars(self, s: &str) -> Self {
+        s.chars().fold(self, |class, ch| class.char(ch))
+    }
+
+    pub fn range(mut self, r: RangeInclusive<char>) -> Self {
+        self.expected = ExpectedCell::default();
+        let (mut lo, hi) = (*r.start(), *r.end());
+        if lo > hi {
+            return self;
+        }
+
+        while lo.is_ascii() && lo <= hi {
+            let b = lo as usize;
+            self.ascii[b / 64] |= 1 << (b % 64);
+            lo = match char::from_u32(lo as u32 + 1) {
+                Some(ch) => ch,
+                None => return self,
+            };
+        }
+
+        if lo <= hi {
+            self.ranges.push((lo, hi));
+            self.normalize();
+        }
+        self
+    }
+
+    pub fn category(mut self, category: Category) -> Self {
+        self.expected = ExpectedCell::default();
+        if !self.categories.contains(&category) {
+            self.categories.push(category);
+        }
+        self
+    }
+
+    pub fn union(mut self, other: CharClass) -> Self {
+        if self.negated || other.negated {
+            return Self {
+                alts: vec![self, other],
+                ..Self::default()
+            };
+        }
+
+        self.expected = ExpectedCell::default();
+        self.ascii[0] |= other.ascii[0];
+        self.ascii[1] |= other.ascii[1];
+        self.ranges.extend(other.ranges);
+        self.normalize();
+        for category in other.categories {
+            self = self.category(category);
+        }
+        self.alts.extend(other.alts);
+        self
+    }
+
+    pub fn negate(mut self) -> Self {
+        self.negated = !self.negated;
+        self.expected = ExpectedCell::default();
+        self
+    }
+
+    pub fn contains(&self, ch: char) -> bool {
+        let found = match ch.is_ascii() {
+            true => {
+                let b = ch as usize;
+                self.ascii[b / 64] & (1 << (b % 64)) != 0
+            },
+            false => self.ranges.binary_search_by(|&(lo, hi)| {
+                if hi < ch {
+                    std::cmp::Ordering::Less
+                } else if lo > ch {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            }).is_ok(),
+        };
+
+        let found = found
+            || self.categories.iter().any(|c| c.matches(ch))
+            || self.alts.iter().any(|c| c.contains(ch));
+        found != self.negated
+    }
+
+    /// The class as a plain predicate, for `take_while` and friends.
+    pub fn predicate(self) -> impl Fn(char) -> bool {
+        move |ch| self.contains(ch)
+    }
+
+    /// The first byte that starts a member (or, with `invert`, a non-member).
+    ///
+    /// `None` when membership can't be decided a byte at a time, i.e. the
+    /// class has members outside ASCII.
+    pub(crate) fn find_byte(&self, bytes: &[u8], invert: bool) -> Option<Option<usize>> {
+        if !self.ranges.is_empty() || !self.categories.is_empty() || !self.alts.is_empty() {
+            return None;
+        }
+
+        // bytes of a multibyte char are never ascii, so they are never in the set
+        let skip = self.negated != invert;
+        let count = self.ascii[0].count_ones() + self.ascii[1].count_ones();
+        if !skip && count <= 3 {
+            let mut set = [0u8; 3];
+            let mut n = 0;
+            for (word, &bits) in self.ascii.iter().enumerate() {
+                let mut bits = bits;
+                while bits != 0 {
+                    set[n] = (word * 64) as u8 + bits.trailing_zeros() as u8;
+                    bits &= bits - 1;
+                    n += 1;
+                }
+            }
+            return Some(u8::find_any(bytes, &set[..n]));
+        }
+
+        Some(bytes.iter().position(|&b| {
+            let member = b < 128 && self.ascii[b as usize / 64] & (1 << (b % 64)) != 0;
+            member != skip
+        }))
+    }
+
+    // sort and merge overlapping or adjacent ranges
+    fn normalize(&mut self) {
+        self.ranges.sort_unstable();
+
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(self.ranges.len());
+        for &(lo, hi) in &self.ranges {
+            match merged.last_mut() {
+                Some(last) if lo as u32 <= last.1 as u32 + 1 => last.1 = last.1.max(hi),
+                _ => merged.push((lo, hi)),
+            }
+        }
+        self.ranges = merged;
+    }
+
+    // every member as an inclusive range, ascii included
+    fn runs(&self) -> Vec<(char, char)> {
+        let mut runs: Vec<(char, char)> = Vec::new();
+        for b in 0..128u8 {
+            if self.ascii[b as usize / 64] & (1 << (b % 64)) == 0 {
+                continue;
+            }
+            let ch = b as char;
+            match runs.last_mut() {
+                Some(last) if last.1 as u32 + 1 == b as u32 => last.1 = ch,
+                _ => runs.push((ch, ch)),
+            }
+        }
+        runs.extend(&self.ranges);
+        runs
+    }
+
+    pub(crate) fn expected(&self) -> Expected {
+        self.expected.0.get_or_init(|| self.build_expected()).clone()
+    }
+
+    fn build_expected(&self) -> Expected {
+        let runs = self.runs();
+        let simple = !self.negated && self.categories.is_empty() && self.alts.is_empty();
+
+        if simple && runs.iter().all(|(lo, hi)| lo == hi) {
+            return match runs.as_slice() {
+                [(ch, _)] => Expected::literal(ch.to_string()),
+                _ => Expected::OneOf(runs.iter().map(|(ch, _)| ch.to_string()).collect()),
+            };
+        }
+
+        Expected::pattern(self.describe())
+    }
+
+    fn describe(&self) -> String {
+        let mut s = String::from("[");
+        if self.negated {
+            s.push('^');
+        }
+        for (lo, hi) in self.runs() {
+            match lo == hi {
+                true => write!(s, "{}", lo.escape_debug()),
+                false => write!(s, "{}-{}", lo.escape_debug(), hi.escape_debug()),
+            }.unwrap();
+        }
+        for category in &self.categories {
+            write!(s, "[:{}:]", category.expected()).unwrap();
+        }
+        for alt in &self.alts {
+            s.push_str(&alt.describe());
+        }
+        s.push(']');
+        s
+    }
+}
@@ -0,0 +1,352 @@
+mod class;
+mod nocase;
+mod trie;
+
+pub use class::CharClass;
+pub use nocase::{Fold, NoCase};
+pub(crate) use nocase::{match_ascii, match_unicode, Mismatch};
+pub(crate) use trie::Trie;
+
+use std::fmt;
+use std::slice;
+
+use unicode_general_category::{get_general_category, GeneralCategory};
+
+use crate::error::{Expected, ParseError};
+use crate::parser::{Input, Parse, ParseResult};
+
+#[derive(Default)]
+pub struct Any;
+
+impl Any {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<I> Parse<I> for Any
+where
+    I: Input,
+{
+    type Output = I::Slice;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        match input.next_item() {
+            Some((_, len)) => {
+                let (x, input) = input.take_split(len);
+                Ok((x.into_slice(), input))
+            },
+            None => Err(ParseError::end(input).expect(Expected::label("anything")).need(1)),
+        }
+    }
+}
+
+/// Matches only at the end of the input.
+#[derive(Default)]
+pub struct Eof;
+
+impl Eof {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<I> Parse<I> for Eof
+where
+    I: Input,
+{
+    type Output = ();
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        match input.input_len() {
+            // partial input may still continue
+            0 if input.is_partial() => Err(ParseError::end(input).expect(Expected::label("end of input"))),
+            0 => Ok(((), input)),
+            _ => Err(ParseError::invalid(input, Expected::label("end of input"))),
+        }
+    }
+}
+
+/// A Unicode character class matching a single `char`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Category {
+    Alphabetic,
+    Alphanumeric,
+    /// Decimal digits (`Nd`) in any script.
+    Digit,
+    Punctuation,
+    Whitespace,
+    XidStart,
+    XidContinue,
+}
+
+impl Category {
+    pub fn matches(&self, ch: char) -> bool {
+        match self {
+            Category::Alphabetic => ch.is_alphabetic(),
+            Category::Alphanumeric => ch.is_alphanumeric(),
+            Category::Digit => get_general_category(ch) == GeneralCategory::DecimalNumber,
+            Category::Punctuation => get_general_category(ch).abbreviation().starts_with('P'),
+            Category::Whitespace => ch.is_whitespace(),
+            Category::XidStart => unicode_ident::is_xid_start(ch),
+            Category::XidContinue => unicode_ident::is_xid_continue(ch),
+        }
+    }
+
+    pub(crate) fn expected(&self) -> Expected {
+        Expected::label(match self {
+            Category::Alphabetic => "letter",
+            Category::Alphanumeric => "letter or digit",
+            Category::Digit => "digit",
+            Category::Punctuation => "punctuation",
+            Category::Whitespace => "whitespace",
+            Category::XidStart => "identifier start",
+            Category::XidContinue => "identifier character",
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct Digit;
+
+impl Digit {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[derive(Default)]
+pub struct Letter;
+
+impl Letter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+/// An element of a token slice that `One`, `OneOf` and `Seq` can match.
+pub trait Token: PartialEq + fmt::Debug + Sized {
+    /// How a run of tokens is shown in error messages.
+    fn describe(xs: &[Self]) -> Expected {
+        match xs {
+            [x] => Expected::pattern(format!("{:?}", x)),
+            _ => Expected::pattern(format!("{:?}", xs)),
+        }
+    }
+
+    /// The position of the first occurrence of `needle` in `haystack`.
+    fn find(haystack: &[Self], needle: &[Self]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        haystack.windows(needle.len()).position(|w| w == needle)
+    }
+
+    /// The position of the first item of `haystack` that is in `set`.
+    fn find_any(haystack: &[Self], set: &[Self]) -> Option<usize> {
+        haystack.iter().position(|x| set.contains(x))
+    }
+}
+
+impl Token for u8 {
+    fn describe(xs: &[Self]) -> Expected {
+        Expected::literal(String::from_utf8_lossy(xs))
+    }
+
+    // memchr picks SSE2/AVX2 or a word-at-a-time fallback at runtime
+    #[inline]
+    fn find(haystack: &[Self], needle: &[Self]) -> Option<usize> {
+        match needle {
+            [] => Some(0),
+            [b] => memchr::memchr(*b, haystack),
+            // short needles don't pay for building a memmem searcher
+            [b, rest @ ..] if rest.len() <= 2 => {
+                let mut start = 0;
+                while let Some(idx) = memchr::memchr(*b, &haystack[start..]) {
+                    let idx = start + idx;
+                    if haystack[idx + 1..].starts_with(rest) {
+                        return Some(idx);
+                    }
+                    start = idx + 1;
+                }
+                None
+            },
+            _ => memchr::memmem::find(haystack, needle),
+        }
+    }
+
+    #[inline]
+    fn find_any(haystack: &[Self], set: &[Self]) -> Option<usize> {
+        match set {
+            [] => None,
+            [a] => memchr::memchr(*a, haystack),
+            [a, b] => memchr::memchr2(*a, *b, haystack),
+            [a, b, c] => memchr::memchr3(*a, *b, *c, haystack),
+            _ => {
+                let mut table = [false; 256];
+                for &b in set {
+                    table[b as usize] = true;
+                }
+                haystack.iter().position(|&b| table[b as usize])
+            },
+        }
+    }
+}
+
+pub struct One<T> {
+    pub(crate) val: T,
+    pub(crate) expected: Expected,
+}
+
+impl One<u8> {
+    pub fn new(byte: u8) -> Self {
+        Self::token(byte)
+    }
+}
+
+impl<T: Token> One<T> {
+    pub fn token(val: T) -> Self {
+        Self {
+            expected: T::describe(slice::from_ref(&val)),
+            val,
+        }
+    }
+}
+
+/// Any one of a set of alternatives.
+///
+/// For strings the longest matching alternative wins, whatever the order
+/// they were given in.
+pub struct OneOf<T> {
+    pub(crate) xs: Vec<T>,
+    // built once so failures only copy it
+    pub(crate) expected: Vec<Expected>,
+    // only has alternatives for `OneOf<String>`
+    pub(crate) trie: Trie,
+}
+
+impl<T: Token + Clone> OneOf<T> {
+    pub fn tokens(xs: &[T]) -> Self {
+        Self {
+            xs: xs.to_vec(),
+            expected: xs.iter().map(|x| T::describe(slice::from_ref(x))).collect(),
+            trie: Trie::default(),
+        }
+    }
+}
+
+impl OneOf<String> {
+    pub fn from(xs: &[&str]) -> Self {
+        let xs: Vec<String> = xs.iter().map(|s| s.to_string()).collect();
+
+        Self {
+            expected: vec![Expected::OneOf(xs.as_slice().into())],
+            trie: Trie::new(&xs),
+            xs,
+        }
+    }
+
+    /// Returns the index of the matched alternative instead of the slice.
+    pub fn indexed(self) -> Indexed<Self> {
+        Indexed {
+            inner: self,
+        }
+    }
+
+    pub(crate) fn expected(&self) -> Expected {
+        self.expected[0].clone()
+    }
+}
+
+pub struct Indexed<T> {
+    pub(crate) inner: T,
+}
+
+impl OneOf<char> {
+    pub fn from(s: &str) -> CharClass {
+        CharClass::new().chars(s)
+    }
+}
+
+pub struct Seq<T> {
+    pub(crate) len: usize,
+    pub(crate) seq: Vec<T>,
+    pub(crate) expected: Expected,
+}
+
+impl Seq<char> {
+    pub fn new(s: &str) -> Seq<u8> {
+        Seq::<u8>::new(s.as_bytes())
+    }
+}
+
+impl Seq<u8> {
+    pub fn new(s: &[u8]) -> Self {
+        Self::tokens(s)
+    }
+
+    pub(crate) fn expected(&self) -> Expected {
+        self.expected.clone()
+    }
+}
+
+impl<T: Token + Clone> Seq<T> {
+    pub fn tokens(xs: &[T]) -> Self {
+        Self {
+            seq: xs.to_vec(),
+            len: xs.len(),
+            expected: T::describe(xs),
+        }
+    }
+}
+
+/// A single item accepted by `f`, returned as the item itself.
+///
+/// Errors expect a "matching character" unless given a [`label`](Satisfy::label).
+pub struct Satisfy<F> {
+    pub(crate) f: F,
+    pub(crate) expected: Expected,
+}
+
+impl<F> Satisfy<F> {
+    pub fn new(f: F) -> Self {
+        Self {
+            f,
+            expected: Expected::label("matching character"),
+        }
+    }
+
+    pub fn label(mut self, label: &'static str) -> Self {
+        self.expected = Expected::label(label);
+        self
+    }
+}
+
+impl<I, F> Parse<I> for Satisfy<F>
+where
+    I: Input,
+    F: Fn(I::Item) -> bool,
+{
+    type Output = I::Item;
+
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I> {
+        match input.next_item() {
+            Some((x, len)) if (self.f)(x) => {
+                let (_, input) = input.take_split(len);
+                Ok((x, input))
+            },
+            Some(_) => Err(ParseError::invalid(input, self.expected.clone())),
+            None => Err(ParseError::end(input).expect(self.expected.clone()).need(1)),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Whitespace;
+
+impl Whitespace {
+    pub fn new() -> Self {
+        Self {
+        }
+    }
+}
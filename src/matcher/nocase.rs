@@ -0,0 +1,91 @@
+use caseless::Caseless;
+
+/// Matches the wrapped `Seq` or `OneOf` ignoring case, returning the
+/// input as it was written.
+pub struct NoCase<T> {
+    pub(crate) inner: T,
+    pub(crate) fold: Fold,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fold {
+    /// Only `A-Z` and `a-z` compare equal.
+    Ascii,
+    /// Full Unicode case folding, so `"STRASSE"` matches `"straße"`
+    /// and `"STRAẞE"`.
+    Unicode,
+}
+
+impl<T> NoCase<T> {
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            fold: Fold::Unicode,
+        }
+    }
+
+    pub fn ascii(inner: T) -> Self {
+        Self {
+            inner,
+            fold: Fold::Ascii,
+        }
+    }
+}
+
+pub(crate) enum Mismatch {
+    /// The input is a prefix of a match, `usize` more units would be needed.
+    Short(usize),
+    Invalid,
+}
+
+/// The length of the prefix of `input` that matches `pat` ignoring ASCII case.
+pub(crate) fn match_ascii(input: &[u8], pat: &[u8]) -> Result<usize, Mismatch> {
+    let len = pat.len().min(input.len());
+    if !input[..len].eq_ignore_ascii_case(&pat[..len]) {
+        return Err(Mismatch::Invalid);
+    }
+
+    match len < pat.len() {
+        true => Err(Mismatch::Short(pat.len() - len)),
+        false => Ok(len),
+    }
+}
+
+/// The length of the prefix of `input` that matches `pat` under full case
+/// folding, falling back to the ASCII comparison when both sides are ASCII.
+pub(crate) fn match_unicode(input: &str, pat: &str) -> Result<usize, Mismatch> {
+    if pat.is_ascii() {
+        let head = &input.as_bytes()[..pat.len().min(input.len())];
+        if head.is_ascii() {
+            return match_ascii(input.as_bytes(), pat.as_bytes());
+        }
+    }
+
+    let mut pat = pat.chars().flat_map(fold);
+    let mut expected = pat.next();
+    if expected.is_none() {
+        return Ok(0);
+    }
+
+    for (idx, ch) in input.char_indices() {
+        for x in fold(ch) {
+            match expected {
+                Some(y) if x == y => expected = pat.next(),
+                _ => return Err(Mismatch::Invalid),
+            }
+        }
+        if expected.is_none() {
+            return Ok(idx + ch.len_utf8());
+        }
+    }
+
+    match expected {
+        Some(ch) => Err(Mismatch::Short(ch.len_utf8() + pat.map(char::len_utf8).sum::<usize>())),
+        None => Ok(input.len()),
+    }
+}
+
+// full case folding from CaseFolding.txt, so 'ß' and 'ẞ' both become "ss"
+fn fold(ch: char) -> impl Iterator<Item = char> {
+    std::iter::once(ch).default_case_fold()
+}
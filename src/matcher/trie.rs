@@ -0,0 +1,103 @@
+use crate::error::{ErrorKind, Expected, ParseError};
+
+/// A byte trie over the alternatives of a `OneOf<String>`.
+#[derive(Clone, Debug)]
+pub(crate) struct Trie {
+    nodes: Vec<Node>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Node {
+    // sorted by byte
+    edges: Vec<(u8, usize)>,
+    // index of the alternative ending here
+    end: Option<usize>,
+    // fewest bytes from here to the end of a longer alternative
+    min_tail: Option<usize>,
+}
+
+// a root with no alternatives, so lookups fail instead of indexing nothing
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new::<&[u8]>(&[])
+    }
+}
+
+impl Trie {
+    pub(crate) fn new<S: AsRef<[u8]>>(xs: &[S]) -> Self {
+        let mut trie = Self {
+            nodes: vec![Node::default()],
+        };
+
+        for (idx, x) in xs.iter().enumerate() {
+            let mut node = 0;
+            for &b in x.as_ref() {
+                node = match trie.nodes[node].edges.binary_search_by_key(&b, |&(b, _)| b) {
+                    Ok(pos) => trie.nodes[node].edges[pos].1,
+                    Err(pos) => {
+                        let next = trie.nodes.len();
+                        trie.nodes.push(Node::default());
+                        trie.nodes[node].edges.insert(pos, (b, next));
+                        next
+                    },
+                };
+            }
+            // the first of two equal alternatives wins
+            trie.nodes[node].end.get_or_insert(idx);
+        }
+
+        // children are always pushed after their parent
+        for node in (0..trie.nodes.len()).rev() {
+            trie.nodes[node].min_tail = trie.nodes[node].edges.iter()
+                .filter_map(|&(_, child)| {
+                    let child = &trie.nodes[child];
+                    match child.end {
+                        Some(_) => Some(1),
+                        None => child.min_tail.map(|n| n + 1),
+                    }
+                })
+                .min();
+        }
+
+        trie
+    }
+
+    /// The longest alternative at the start of `bytes`, as `(len, index)`.
+    ///
+    /// When `partial` is set, running out of bytes while a longer
+    /// alternative could still match is reported as `Indeterminate` even if
+    /// a shorter one already did.
+    pub(crate) fn longest<I>(
+        &self,
+        input: I,
+        bytes: &[u8],
+        partial: bool,
+        expected: impl FnOnce() -> Expected,
+    ) -> Result<(usize, usize), ParseError<I>> {
+        let mut node = 0;
+        let mut found = None;
+
+        for (len, b) in bytes.iter().enumerate() {
+            if let Some(idx) = self.nodes[node].end {
+                found = Some((len, idx));
+            }
+            node = match self.nodes[node].edges.binary_search_by_key(b, |&(b, _)| b) {
+                Ok(pos) => self.nodes[node].edges[pos].1,
+                Err(_) => return found.ok_or_else(|| ParseError::invalid(input, expected())),
+            };
+        }
+
+        if let Some(idx) = self.nodes[node].end {
+            found = Some((bytes.len(), idx));
+        }
+
+        match (self.nodes[node].min_tail, found) {
+            (Some(n), _) if partial => {
+                Err(ParseError::new(ErrorKind::Indeterminate, input).expect(expected()).need(n))
+            },
+            (_, Some(found)) => Ok(found),
+            (Some(n), None) => Err(ParseError::eof(input, expected()).need(n)),
+            (None, None) => Err(ParseError::invalid(input, expected())),
+        }
+    }
+}
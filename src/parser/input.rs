@@ -0,0 +1,176 @@
+/// A stream a parser can consume from.
+///
+/// Positions are measured in the input's own units: bytes for `&str`
+/// (always on a char boundary) and elements for slices.
+pub trait Input: Copy {
+    type Item: Copy;
+
+    /// What parsers hand back when they return a run of the input.
+    type Slice: Copy;
+
+    fn into_slice(self) -> Self::Slice;
+
+    /// Whether more input may follow, see [`Partial`].
+    fn is_partial(&self) -> bool {
+        false
+    }
+
+    fn input_len(&self) -> usize;
+
+    /// The next item and the number of units it occupies.
+    fn next_item(&self) -> Option<(Self::Item, usize)>;
+
+    fn is_boundary(&self, idx: usize) -> bool;
+
+    /// Splits the input into the first `idx` units and the remainder.
+    fn take_split(&self, idx: usize) -> (Self, Self);
+
+    /// The position of the first item matching `f`.
+    fn position<F>(&self, f: F) -> Option<usize>
+    where
+        F: Fn(Self::Item) -> bool;
+}
+
+impl<'a> Input for &'a str {
+    type Item = char;
+    type Slice = &'a str;
+
+    #[inline]
+    fn into_slice(self) -> Self::Slice {
+        self
+    }
+
+    #[inline]
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn next_item(&self) -> Option<(Self::Item, usize)> {
+        self.chars().next().map(|ch| (ch, ch.len_utf8()))
+    }
+
+    #[inline]
+    fn is_boundary(&self, idx: usize) -> bool {
+        self.is_char_boundary(idx)
+    }
+
+    #[inline]
+    fn take_split(&self, idx: usize) -> (Self, Self) {
+        (&self[..idx], &self[idx..])
+    }
+
+    #[inline]
+    fn position<F>(&self, f: F) -> Option<usize>
+    where
+        F: Fn(Self::Item) -> bool,
+    {
+        self.char_indices().find(|(_, ch)| f(*ch)).map(|(idx, _)| idx)
+    }
+}
+
+impl<'a, T> Input for &'a [T] {
+    type Item = &'a T;
+    type Slice = &'a [T];
+
+    #[inline]
+    fn into_slice(self) -> Self::Slice {
+        self
+    }
+
+    #[inline]
+    fn input_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn next_item(&self) -> Option<(Self::Item, usize)> {
+        self.first().map(|x| (x, 1))
+    }
+
+    #[inline]
+    fn is_boundary(&self, idx: usize) -> bool {
+        idx <= self.len()
+    }
+
+    #[inline]
+    fn take_split(&self, idx: usize) -> (Self, Self) {
+        self.split_at(idx)
+    }
+
+    #[inline]
+    fn position<F>(&self, f: F) -> Option<usize>
+    where
+        F: Fn(Self::Item) -> bool,
+    {
+        self.iter().position(f)
+    }
+}
+
+/// Input that may be followed by more data.
+///
+/// Parsing a `Partial` input fails with [`ErrorKind::Indeterminate`] instead
+/// of [`ErrorKind::EOF`] when a parser runs out of data before it can
+/// decide, so the caller can read more and retry.
+///
+/// [`ErrorKind::Indeterminate`]: crate::error::ErrorKind::Indeterminate
+/// [`ErrorKind::EOF`]: crate::error::ErrorKind::EOF
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Partial<I>(pub I);
+
+impl<I> Partial<I> {
+    pub fn new(input: I) -> Self {
+        Self(input)
+    }
+
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I> Input for Partial<I>
+where
+    I: Input,
+{
+    type Item = I::Item;
+    type Slice = I::Slice;
+
+    #[inline]
+    fn into_slice(self) -> Self::Slice {
+        self.0.into_slice()
+    }
+
+    #[inline]
+    fn is_partial(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn input_len(&self) -> usize {
+        self.0.input_len()
+    }
+
+    #[inline]
+    fn next_item(&self) -> Option<(Self::Item, usize)> {
+        self.0.next_item()
+    }
+
+    #[inline]
+    fn is_boundary(&self, idx: usize) -> bool {
+        self.0.is_boundary(idx)
+    }
+
+    #[inline]
+    fn take_split(&self, idx: usize) -> (Self, Self) {
+        let (a, b) = self.0.take_split(idx);
+        (Partial(a), Partial(b))
+    }
+
+    #[inline]
+    fn position<F>(&self, f: F) -> Option<usize>
+    where
+        F: Fn(Self::Item) -> bool,
+    {
+        self.0.position(f)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::error::ParseError;
+use crate::parser::{Input, Parse};
+
+/// Runs a parser repeatedly over an input, yielding each result.
+///
+/// Iteration ends at the first failure; the remaining input and the error
+/// that ended it stay available afterwards.
+pub struct ParseIter<'p, P, I> {
+    pub(crate) p: &'p P,
+    pub(crate) input: I,
+    pub(crate) error: Option<ParseError<I>>,
+    pub(crate) done: bool,
+}
+
+impl<'p, P, I> ParseIter<'p, P, I> {
+    pub fn new(p: &'p P, input: I) -> Self {
+        Self {
+            p,
+            input,
+            error: None,
+            done: false,
+        }
+    }
+
+    /// The input not yet consumed.
+    pub fn remaining(&self) -> I
+    where
+        I: Copy,
+    {
+        self.input
+    }
+
+    /// The failure that ended iteration, if it has ended with one.
+    pub fn error(&self) -> Option<&ParseError<I>> {
+        self.error.as_ref()
+    }
+
+    /// The remaining input and the error that ended iteration.
+    pub fn finish(self) -> (I, Option<ParseError<I>>) {
+        (self.input, self.error)
+    }
+}
+
+impl<'p, P, I> Iterator for ParseIter<'p, P, I>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    type Item = P::Output;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.p.parse(self.input) {
+            Ok((res, xs)) => {
+                // a match that consumes nothing would repeat forever
+                self.done = xs.input_len() == self.input.input_len();
+                self.input = xs;
+                Some(res)
+            },
+            Err(e) => {
+                self.done = true;
+                self.error = Some(e);
+                None
+            },
+        }
+    }
+}
@@ -1,48 +1,108 @@
-use crate::combinator::{Map, And, Or, Skip, Left, Right};
+mod input;
+mod iter;
+
+pub use input::{Input, Partial};
+pub use iter::ParseIter;
+
 use crate::error::ParseError;
 
+pub type ParseResult<O, I> = Result<(O, I), ParseError<I>>;
+
+/// A parser over any [`Input`].
+///
+/// Combinators and matchers implement this once for every input type they
+/// support; the fluent builder methods live on the per-input traits
+/// [`ParseStr`](crate::str_parser::ParseStr) and
+/// [`ParseByte`](crate::byte_parser::ParseByte).
 pub trait Parse<I> {
     type Output;
 
-    fn parse<'a>(&self, input: &'a [I]) -> Result<(Self::Output, &'a [I]), ParseError<&'a [I]>>;
+    fn parse(&self, input: I) -> ParseResult<Self::Output, I>;
 
-    fn then<P>(self, other: P) -> And<Self, P>
+    /// Finds the first position at which the parser matches.
+    ///
+    /// The default tries every item boundary in turn, including the end of
+    /// the input; matchers override it with a faster search.
+    fn scan(&self, input: I) -> Result<usize, ParseError<I>>
     where
-        P: Parse<I>,
-        Self: Sized + 'static,
+        I: Input,
     {
-        And::new(self, other)
+        default_scan(self, input)
     }
+}
 
-    fn map<'a, F, B>(self, f: F) -> Map<Self, F>
-    where
-        F: Fn(Self::Output) -> B,
-        Self: Sized + 'static,
-    {
-        Map::new(self, f)
-    }
+/// The item-by-item search behind [`Parse::scan`], for overrides that only
+/// have a fast path for some inputs.
+pub(crate) fn default_scan<I, P>(p: &P, input: I) -> Result<usize, ParseError<I>>
+where
+    I: Input,
+    P: Parse<I> + ?Sized,
+{
+    let mut idx = 0;
 
-    fn or<P>(self, other: P) -> Or<Self, P>
-    where
-        P: Parse<I>,
-        Self: Sized + 'static,
-    {
-        Or::new(self, other)
-    }
+    loop {
+        let (_, rest) = input.take_split(idx);
 
-    fn skip_left<P>(self, other: P) -> Skip<Left, Self, P>
-    where
-        P: Parse<I>,
-        Self: Sized + 'static,
-    {
-        Skip::<Left, _, _>::new(self, other)
+        match p.parse(rest) {
+            Ok(_) => return Ok(idx),
+            Err(e) if !e.is_recoverable() => return Err(e),
+            Err(e) => match rest.next_item() {
+                Some((_, len)) => idx += len,
+                None => {
+                    let mut err = ParseError::end(rest);
+                    err.expected = e.expected;
+                    return Err(err);
+                },
+            },
+        }
     }
+}
 
-    fn skip_right<P>(self, other: P) -> Skip<Right, Self, P>
-    where
-        P: Parse<I>,
-        Self: Sized + 'static,
-    {
-        Skip::<Right, _, _>::new(self, other)
+/// Runs a parser written for complete input on [`Partial`] input.
+///
+/// Matchers implement `Parse<Partial<_>>` through this, so running out of
+/// data becomes [`ErrorKind::Indeterminate`](crate::error::ErrorKind::Indeterminate).
+pub(crate) fn parse_partial<I, P>(p: &P, input: Partial<I>) -> ParseResult<P::Output, Partial<I>>
+where
+    P: Parse<I>,
+{
+    match p.parse(input.0) {
+        Ok((res, rest)) => Ok((res, Partial(rest))),
+        Err(e) => Err(e.into_partial()),
     }
 }
+
+/// The [`Parse::scan`] counterpart of [`parse_partial`].
+pub(crate) fn scan_partial<I, P>(p: &P, input: Partial<I>) -> Result<usize, ParseError<Partial<I>>>
+where
+    I: Input,
+    P: Parse<I>,
+{
+    p.scan(input.0).map_err(|e| e.into_partial())
+}
+
+macro_rules! impl_partial {
+    ($input:ty => $($t:ty),+ $(,)?) => {
+        $(
+            impl<'a> crate::parser::Parse<crate::parser::Partial<$input>> for $t {
+                type Output = <$t as crate::parser::Parse<$input>>::Output;
+
+                fn parse(
+                    &self,
+                    input: crate::parser::Partial<$input>,
+                ) -> crate::parser::ParseResult<Self::Output, crate::parser::Partial<$input>> {
+                    crate::parser::parse_partial(self, input)
+                }
+
+                fn scan(
+                    &self,
+                    input: crate::parser::Partial<$input>,
+                ) -> Result<usize, crate::error::ParseError<crate::parser::Partial<$input>>> {
+                    crate::parser::scan_partial(self, input)
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use impl_partial;
@@ -1,103 +1,241 @@
-use std::cmp::Ordering;
+use crate::combinator::TakeWhile;
+use crate::error::{Expected, ParseError};
+use crate::matcher::{match_ascii, match_unicode, Category, CharClass, Digit, Fold, Indexed, Letter, Mismatch, NoCase, OneOf, Seq, Token, Whitespace};
+use crate::parser::{impl_partial, Input, Parse, ParseResult, Partial};
 
-use crate::str_parser::ParseStr;
-use crate::matcher::{Any, Digit, Letter, OneOf, Seq, Whitespace};
+impl<'a> Parse<&'a str> for Category {
+    type Output = char;
 
-impl<'a> ParseStr<'a> for Any {
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
+        match input.chars().next() {
+            Some(ch) if self.matches(ch) => Ok((ch, &input[ch.len_utf8()..])),
+            Some(_) => Err(ParseError::invalid(input, self.expected())),
+            None => Err(ParseError::eof(input, self.expected()).need(1)),
+        }
+    }
+}
+
+impl<'a> Parse<&'a str> for CharClass {
+    type Output = char;
+
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
+        match input.chars().next() {
+            Some(ch) if self.contains(ch) => Ok((ch, &input[ch.len_utf8()..])),
+            Some(_) => Err(ParseError::invalid(input, self.expected())),
+            None => Err(ParseError::eof(input, self.expected()).need(1)),
+        }
+    }
+
+    #[inline]
+    fn scan(&self, input: &'a str) -> Result<usize, ParseError<&'a str>> {
+        let found = match self.find_byte(input.as_bytes(), false) {
+            Some(found) => found,
+            None => input.char_indices().find(|(_, ch)| self.contains(*ch)).map(|(idx, _)| idx),
+        };
+
+        match found {
+            Some(idx) => Ok(idx),
+            None => Err(ParseError::eof(&input[input.len()..], self.expected())),
+        }
+    }
+}
+
+impl<'a> Parse<&'a str> for TakeWhile<CharClass> {
     type Output = &'a str;
 
-    fn parse(&self, input: &'a str) -> Result<(Self::Output, &'a str), &'a str> {
-        match input.len() {
-            0 => Err(input), _ => Ok((&input[0..1], &input[1..]))
+    #[inline]
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
+        let idx = take_class(&self.f, input).unwrap_or(input.len());
+        Ok(input.split_at(idx))
+    }
+}
+
+impl<'a> Parse<Partial<&'a str>> for TakeWhile<CharClass> {
+    type Output = &'a str;
+
+    #[inline]
+    fn parse(&self, input: Partial<&'a str>) -> ParseResult<Self::Output, Partial<&'a str>> {
+        match take_class(&self.f, input.0) {
+            Some(idx) => {
+                let (xs, input) = input.take_split(idx);
+                Ok((xs.0, input))
+            },
+            None => Err(ParseError::end(input)),
         }
     }
 }
 
-impl<'a> ParseStr<'a> for Digit {
+// the end of the run of class members at the start of `input`
+fn take_class(class: &CharClass, input: &str) -> Option<usize> {
+    match class.find_byte(input.as_bytes(), true) {
+        Some(found) => found,
+        None => input.char_indices().find(|(_, ch)| !class.contains(*ch)).map(|(idx, _)| idx),
+    }
+}
+
+impl<'a> Parse<&'a str> for Digit {
     type Output = &'a str;
 
-    fn parse(&self, input: &'a str) -> Result<(Self::Output, &'a str), &'a str> {
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
         match input.chars().next() {
-            Some(ch) if ch.is_numeric() => {
-                Ok((&input[0..1], &input[1..]))
+            Some(ch) if Category::Digit.matches(ch) => {
+                Ok(input.split_at(ch.len_utf8()))
             },
-            _ => Err(input),
+            Some(_) => Err(ParseError::invalid(input, Expected::label("digit"))),
+            None => Err(ParseError::eof(input, Expected::label("digit")).need(1)),
         }
     }
 }
 
-impl<'a> ParseStr<'a> for Letter {
+impl<'a> Parse<&'a str> for Letter {
     type Output = &'a str;
 
-    fn parse(&self, input: &'a str) -> Result<(Self::Output, &'a str), &'a str> {
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
         match input.chars().next() {
-            Some(ch) if ch.is_numeric() => {
-                Ok((&input[0..1], &input[1..]))
+            Some(ch) if ch.is_alphabetic() => {
+                Ok(input.split_at(ch.len_utf8()))
             },
-            _ => Err(input),
+            Some(_) => Err(ParseError::invalid(input, Expected::label("letter"))),
+            None => Err(ParseError::eof(input, Expected::label("letter")).need(1)),
         }
     }
 }
 
-impl<'a> ParseStr<'a> for OneOf<String> {
+impl<'a> Parse<&'a str> for OneOf<String> {
     type Output = &'a str;
 
-    fn parse(&self, input: &'a str) -> Result<(Self::Output, &'a str), &'a str> {
-        for s in &self.xs {
-            if input.starts_with(s.as_str()) {
-                return Ok((&input[..s.len()], &input[s.len()..]));
-            }
-        }
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
+        let (len, _) = self.trie.longest(input, input.as_bytes(), false, || self.expected())?;
+        Ok(input.split_at(len))
+    }
+}
+
+impl<'a> Parse<Partial<&'a str>> for OneOf<String> {
+    type Output = &'a str;
+
+    fn parse(&self, input: Partial<&'a str>) -> ParseResult<Self::Output, Partial<&'a str>> {
+        let (len, _) = self.trie.longest(input, input.0.as_bytes(), true, || self.expected())?;
+        let (xs, input) = input.take_split(len);
+        Ok((xs.0, input))
+    }
+}
+
+impl<'a> Parse<&'a str> for Indexed<OneOf<String>> {
+    type Output = usize;
 
-        Err(input)
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
+        let (len, idx) = self.inner.trie.longest(input, input.as_bytes(), false, || self.inner.expected())?;
+        Ok((idx, &input[len..]))
     }
 }
 
-impl<'a> ParseStr<'a> for OneOf<[u8; 4]> {
+impl<'a> Parse<Partial<&'a str>> for Indexed<OneOf<String>> {
+    type Output = usize;
+
+    fn parse(&self, input: Partial<&'a str>) -> ParseResult<Self::Output, Partial<&'a str>> {
+        let (len, idx) = self.inner.trie.longest(input, input.0.as_bytes(), true, || self.inner.expected())?;
+        Ok((idx, input.take_split(len).1))
+    }
+}
+
+impl<'a> Parse<&'a str> for NoCase<Seq<u8>> {
     type Output = &'a str;
 
-    fn parse(&self, input: &'a str) -> Result<(Self::Output, &'a str), &'a str> {
-        let bytes = input.as_bytes();
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
+        let res = match (self.fold, std::str::from_utf8(&self.inner.seq)) {
+            (Fold::Unicode, Ok(pat)) => match_unicode(input, pat),
+            _ => match_ascii(input.as_bytes(), &self.inner.seq),
+        };
 
-        let mut temp = [0u8; 4];
-        temp.copy_from_slice(&bytes[0..4]);
+        match res {
+            Ok(len) if input.is_char_boundary(len) => Ok(input.split_at(len)),
+            Err(Mismatch::Short(n)) => Err(ParseError::eof(input, self.inner.expected()).need(n)),
+            _ => Err(ParseError::invalid(input, self.inner.expected())),
+        }
+    }
+}
+
+impl<'a> Parse<&'a str> for NoCase<OneOf<String>> {
+    type Output = &'a str;
+
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
+        let mut longest = None;
+        let mut needed = None;
 
-        match self.xs.contains(&temp) {
-            true => Ok((&input[0..1], &input[1..])),
-            _ => Err(input),
+        for s in &self.inner.xs {
+            let res = match self.fold {
+                Fold::Unicode => match_unicode(input, s),
+                Fold::Ascii => match_ascii(input.as_bytes(), s.as_bytes()),
+            };
+
+            match res {
+                Ok(len) if input.is_char_boundary(len) => longest = longest.max(Some(len)),
+                Err(Mismatch::Short(n)) => needed = Some(needed.map_or(n, |m: usize| m.min(n))),
+                _ => {},
+            }
+        }
+
+        if let Some(len) = longest {
+            return Ok(input.split_at(len));
+        }
+
+        let expected = self.inner.expected();
+        match needed {
+            Some(n) => Err(ParseError::eof(input, expected).need(n)),
+            None => Err(ParseError::invalid(input, expected)),
         }
     }
 }
 
-impl<'a> ParseStr<'a> for Seq<u8> {
+impl<'a> Parse<&'a str> for Seq<u8> {
     type Output = &'a str;
 
-    fn parse(&self, input: &'a str) -> Result<(Self::Output, &'a str), &'a str> {
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
         let bytes = input.as_bytes();
         let len = self.len;
         let seq = &self.seq;
 
         if bytes.len() < len {
-            return Err(input);
+            return match seq.starts_with(bytes) {
+                true => Err(ParseError::eof(input, self.expected()).need(len - bytes.len())),
+                false => Err(ParseError::invalid(input, self.expected())),
+            };
         }
 
-        match &bytes[0..len] == seq {
-            true => Ok((&input[0..len], &input[len..])),
-            false => Err(input)
+        // a needle that isn't valid utf-8 may still end inside a char
+        match &bytes[0..len] == seq && input.is_char_boundary(len) {
+            true => Ok(input.split_at(len)),
+            false => Err(ParseError::invalid(input, self.expected())),
+        }
+    }
+
+    #[inline]
+    fn scan(&self, input: &'a str) -> Result<usize, ParseError<&'a str>> {
+        // a needle that isn't valid utf-8 could only match inside a char
+        let found = match std::str::from_utf8(&self.seq) {
+            Ok(_) => u8::find(input.as_bytes(), &self.seq),
+            Err(_) => None,
+        };
+
+        match found {
+            Some(idx) => Ok(idx),
+            None => Err(ParseError::eof(&input[input.len()..], self.expected())),
         }
     }
 }
 
-impl<'a> ParseStr<'a> for Whitespace {
+impl<'a> Parse<&'a str> for Whitespace {
     type Output = &'a str;
 
-    fn parse(&self, input: &'a str) -> Result<(Self::Output, &'a str), &'a str> {
+    fn parse(&self, input: &'a str) -> ParseResult<Self::Output, &'a str> {
         match input.chars().next() {
             Some(ch) if ch.is_whitespace() => {
-                Ok((&input[0..1], &input[1..]))
+                Ok(input.split_at(ch.len_utf8()))
             },
-            _ => Err(input),
+            Some(_) => Err(ParseError::invalid(input, Expected::label("whitespace"))),
+            None => Err(ParseError::eof(input, Expected::label("whitespace")).need(1)),
         }
     }
 }
 
+impl_partial!(&'a str => Category, CharClass, Digit, Letter, NoCase<Seq<u8>>, NoCase<OneOf<String>>, Seq<u8>, Whitespace);
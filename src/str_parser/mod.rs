@@ -1,21 +1,14 @@
-mod combinator;
 mod matcher;
 
-pub use combinator::*;
-pub use matcher::*;
-
-use crate::combinator::{And, Map, Or};
+use crate::combinator::{And, BoxedParser, Context, ManyN, Map, Optional, Or};
 use crate::combinator::{Skip, Left, Right, SkipN};
+use crate::parser::{Parse, ParseIter};
 
-pub trait ParseStr<'a> {
-    type Output;
-
-    fn parse(&self, input: &'a str) -> Result<(Self::Output, &'a str), &'a str>;
-
+pub trait ParseStr<'a>: Parse<&'a str> {
     fn then<P>(self, other: P) -> And<Self, P>
     where
         P: ParseStr<'a>,
-        Self: Sized + 'static,
+        Self: Sized,
     {
         And::new(self, other)
     }
@@ -31,7 +24,7 @@ pub trait ParseStr<'a> {
     fn or<P>(self, other: P) -> Or<Self, P>
     where
         P: ParseStr<'a>,
-        Self: Sized + 'static,
+        Self: Sized,
     {
         Or::new(self, other)
     }
@@ -39,7 +32,7 @@ pub trait ParseStr<'a> {
     fn skip_left<P>(self, other: P) -> Skip<Left, Self, P>
     where
         P: ParseStr<'a>,
-        Self: Sized + 'static,
+        Self: Sized,
     {
         Skip::<Left, _, _>::new(self, other)
     }
@@ -47,15 +40,56 @@ pub trait ParseStr<'a> {
     fn skip_right<P>(self, other: P) -> Skip<Right, Self, P>
     where
         P: ParseStr<'a>,
-        Self: Sized + 'static,
+        Self: Sized,
     {
         Skip::<Right, _, _>::new(self, other)
     }
 
     fn skip_n(self, n: usize) -> SkipN<Self>
     where
-        Self: Sized + 'static,
+        Self: Sized,
     {
         SkipN::new(self, n)
     }
+
+    fn opt(self) -> Optional<Self>
+    where
+        Self: Sized,
+    {
+        Optional::new(self)
+    }
+
+    fn repeat(self, n: usize) -> ManyN<Self>
+    where
+        Self: Sized,
+    {
+        ManyN::new(self, n)
+    }
+
+    fn iter(&self, input: &'a str) -> ParseIter<'_, Self, &'a str>
+    where
+        Self: Sized,
+    {
+        ParseIter::new(self, input)
+    }
+
+    fn context(self, label: &'static str) -> Context<Self>
+    where
+        Self: Sized,
+    {
+        Context::new(self, label)
+    }
+
+    fn boxed<'p>(self) -> BoxedParser<'p, &'a str, Self::Output>
+    where
+        Self: Sized + 'p,
+    {
+        BoxedParser::new(self)
+    }
+}
+
+impl<'a, P> ParseStr<'a> for P
+where
+    P: Parse<&'a str>,
+{
 }
@@ -0,0 +1,125 @@
+use crate::bytes::*;
+use crate::prelude::*;
+
+type Request<'a> = ((&'a [u8], &'a [u8]), &'a [u8]);
+type Headers<'a> = Vec<(&'a [u8], &'a [u8])>;
+
+fn http_parser<'a>() -> impl ParseByte<'a, Output = (Request<'a>, Headers<'a>)> {
+    let methods = [
+        "GET", "POST", "PUT", "DELETE", "HEAD",
+        "CONNECT", "OPTIONS", "TRACE", "PATCH",
+    ];
+    let method = OneOf::<String>::from(&methods);
+
+    let req = method.skip_right(one_byte(b' '))
+        .then(take_until(byte_seq(b" ")))
+        .skip_n(1)
+        .then(take_until(byte_seq(b"\r\n")))
+        .skip_n(2);
+
+    let header = take_until(byte_seq(b":"))
+        .skip_n(2)
+        .then(take_until(byte_seq(b"\r\n")))
+        .skip_n(2);
+
+    req.then(many1(header)).skip_n(2)
+}
+
+#[test]
+fn single_input() {
+    let input = b"GET /index.html HTTP/1.1\r\n\
+        User-Agent: Mozilla/4.0 (compatible; MSIE5.01; Windows NT)\r\n\
+        Accept-Language: en-us\r\n\
+        Accept-Encoding: gzip, deflate\r\n\
+        Connection: Keep-Alive\r\n\r\n";
+
+    let parser = http_parser();
+    let (((method, path), version), headers) = parser.parse(input).unwrap().0;
+    assert_eq!(method, b"GET");
+    assert_eq!(path, b"/index.html");
+    assert_eq!(version, b"HTTP/1.1");
+    assert_eq!(headers.len(), 4);
+    assert_eq!(headers[3], (&b"Connection"[..], &b"Keep-Alive"[..]));
+}
+
+#[test]
+fn large_input() {
+    let input = include_str!("large.txt").to_string();
+    let input = input.replace("\r\n", "\n").replace('\n', "\r\n");
+    // the last request has no blank line after it
+    let input = input + "\r\n";
+
+    // header names are found with take_until(':'), so each request is
+    // parsed on its own to stop them running on into the next one
+    let parser = http_parser();
+    let mut count = 0;
+    for request in input.split_inclusive("\r\n\r\n") {
+        let (((_, version), headers), rest) = parser.parse(request.as_bytes()).unwrap();
+        assert!(version.starts_with(b"HTTP/1."));
+        assert!(!headers.is_empty());
+        assert_eq!(rest, b"");
+        count += 1;
+    }
+
+    assert_eq!(count, 275);
+}
+
+#[test]
+fn matchers() {
+    let input: &[u8] = b"12ab \xc3\xa9x";
+
+    let (digits, xs) = many1(Digit::new()).parse(input).unwrap();
+    assert_eq!(digits, vec![b"1", b"2"]);
+
+    let (letters, xs) = ManyN::new(Letter::new(), 2).parse(xs).unwrap();
+    assert_eq!(letters, vec![b"a", b"b"]);
+
+    let (_, xs) = Whitespace::new().parse(xs).unwrap();
+    let (ch, xs) = OneOf::<char>::from("xé").parse(xs).unwrap();
+    assert_eq!(ch, 'é');
+
+    assert!(Digit::new().parse(xs).is_err());
+    assert_eq!(Optional::new(Digit::new()).parse(xs).unwrap(), (None, &b"x"[..]));
+    assert_eq!(take_while(|b| *b != b'!').parse(xs).unwrap(), (&b"x"[..], &b""[..]));
+}
+
+#[test]
+fn scan_token_sets() {
+    let input: &[u8] = b"Host: example.com\r\nAccept: */*\r\n";
+
+    assert_eq!(take_until(byte_seq(b"\r\n")).parse(input).unwrap().0, b"Host: example.com");
+    assert_eq!(take_until(one_byte(b':')).parse(input).unwrap().0, b"Host");
+    assert_eq!(take_until(crate::tokens::one_of(b".*")).parse(input).unwrap().0, b"Host: example");
+    assert!(take_until(byte_seq(b"\n\n")).parse(input).is_err());
+}
+
+#[test]
+fn separated_list() {
+    let input: &[u8] = b"gzip, deflate\r\n";
+    let encoding = take_while(|b| b.is_ascii_alphanumeric());
+
+    let (res, rest) = sep_by1(encoding, byte_seq(b", ")).parse(input).unwrap();
+    assert_eq!(res, vec![&b"gzip"[..], b"deflate"]);
+    assert_eq!(rest, b"\r\n");
+}
+
+#[test]
+fn flat_seq_and_alt() {
+    let input: &[u8] = b"POST /x\r\n";
+    let method = alt((byte_seq(b"GET"), byte_seq(b"POST")));
+    let line = seq((method, one_byte(b' '), take_until(byte_seq(b"\r\n"))));
+
+    let ((method, _, path), rest) = line.parse(input).unwrap();
+    assert_eq!((method, path, rest), (&b"POST"[..], &b"/x"[..], &b"\r\n"[..]));
+}
+
+#[test]
+fn lookahead() {
+    let input: &[u8] = b"\r\n";
+    let end = terminated(byte_seq(b"\r\n"), eof());
+
+    assert_eq!(end.parse(input).unwrap(), (&b"\r\n"[..], &b""[..]));
+    assert!(end.parse(&b"\r\nx"[..]).is_err());
+    assert_eq!(peek(one_byte(b'\r')).parse(input).unwrap().1, input);
+    assert_eq!(not(one_byte(b'x')).parse(input).unwrap(), ((), input));
+}
@@ -0,0 +1,101 @@
+use crate::prelude::*;
+use crate::str::*;
+
+#[test]
+fn ranges_and_unions() {
+    let hex = CharClass::hex_digit();
+    assert!(hex.contains('a') && hex.contains('F') && hex.contains('7'));
+    assert!(!hex.contains('g'));
+
+    let greek = CharClass::new().range('α'..='ω').union(CharClass::digit());
+    assert!(greek.contains('λ') && greek.contains('3'));
+    assert!(!greek.contains('a') && !greek.contains('Ω'));
+
+    let (xs, rest) = many1(greek).parse("αβ1γ!").unwrap();
+    assert_eq!(xs, vec!['α', 'β', '1', 'γ']);
+    assert_eq!(rest, "!");
+
+    let word = CharClass::new().category(Category::Alphabetic).char('_');
+    assert_eq!(take_while(word.predicate()).parse("naïve_x y").unwrap(), ("naïve_x", " y"));
+}
+
+#[test]
+fn negation() {
+    let unquoted = CharClass::new().chars("\"\\").negate();
+    assert_eq!(take_while(unquoted.clone().predicate()).parse("ab→c\"").unwrap(), ("ab→c", "\""));
+
+    // a negated class keeps its meaning when combined with another
+    let class = unquoted.union(CharClass::new().char('"'));
+    assert!(class.contains('"') && class.contains('x'));
+    assert!(!class.contains('\\'));
+}
+
+#[test]
+fn short_and_multibyte_input() {
+    let class = one_of("xé");
+
+    assert_eq!(class.parse("é").unwrap(), ('é', ""));
+    assert_eq!(class.parse("x").unwrap(), ('x', ""));
+    assert_eq!(class.parse("").unwrap_err().needed(), Some(1));
+
+    let err = class.parse("y").unwrap_err();
+    assert_eq!(err.to_string(), "expected one of 'x', 'é', found 'y'");
+
+    let err = CharClass::hex_digit().parse("z").unwrap_err();
+    assert_eq!(err.to_string(), "expected [0-9A-Fa-f], found 'z'");
+
+    let input: &[u8] = "é".as_bytes();
+    let err = class.parse(&input[..1]).unwrap_err();
+    assert_eq!(err.needed(), Some(1));
+    assert_eq!(class.parse(input).unwrap(), ('é', &b""[..]));
+}
+
+#[test]
+fn take_while_in_class() {
+    let token = take_while_in(CharClass::alphanumeric().chars("-_"));
+    assert_eq!(token.parse("x-ray_1: y").unwrap(), ("x-ray_1", ": y"));
+    assert_eq!(token.parse("abc").unwrap(), ("abc", ""));
+
+    let value = take_while_in(CharClass::new().chars("\r\n").negate());
+    assert_eq!(value.parse("naïve →\r\n").unwrap(), ("naïve →", "\r\n"));
+    assert!(value.parse(Partial("naïve")).unwrap_err().is_incomplete());
+
+    let greek = take_while_in(CharClass::new().range('α'..='ω'));
+    assert_eq!(greek.parse("αβγ!").unwrap(), ("αβγ", "!"));
+
+    let input: &[u8] = b"\xce\xb1\xce\xb2\xce\xb3\xff";
+    let (xs, rest) = crate::bytes::take_while_in(CharClass::new().range('α'..='ω')).parse(input).unwrap();
+    assert_eq!(xs, "αβγ".as_bytes());
+    assert_eq!(rest, b"\xff");
+}
+
+#[test]
+fn invalid_utf8_bytes() {
+    let greek = CharClass::new().range('α'..='ω');
+    let any = CharClass::new().negate();
+
+    // continuation bytes and bytes that can never start a char
+    for input in [&b"\x80"[..], b"\xbf", b"\xc0\xaf", b"\xf8", b"\xff\xff"] {
+        assert_eq!(any.parse(input).unwrap_err().kind(), ErrorKind::Invalid);
+        assert_eq!(any.parse(Partial(input)).unwrap_err().kind(), ErrorKind::Invalid);
+    }
+
+    // a lead byte followed by something other than a continuation
+    assert_eq!(any.parse(&b"\xe2\x41"[..]).unwrap_err().kind(), ErrorKind::Invalid);
+    assert_eq!(any.parse(&b"\xe2\x82"[..]).unwrap_err().needed(), Some(1));
+    assert!(any.parse(Partial(&b"\xe2\x82"[..])).unwrap_err().is_incomplete());
+
+    // a run ends at the invalid byte instead of waiting for more data
+    let run = crate::bytes::take_while_in(greek);
+    let input: &[u8] = b"\xce\xb1\x80";
+    assert_eq!(run.parse(input).unwrap(), ("α".as_bytes(), &b"\x80"[..]));
+    assert_eq!(run.parse(Partial(input)).unwrap(), ("α".as_bytes(), Partial(&b"\x80"[..])));
+}
+
+#[test]
+fn take_until_class() {
+    assert_eq!(take_until(one_of(":;")).parse("key; x").unwrap(), ("key", "; x"));
+    assert_eq!(take_until(one_of("=:;,")).parse("a-b,c").unwrap(), ("a-b", ",c"));
+    assert_eq!(take_until(CharClass::new().range('α'..='ω')).parse("abcλ").unwrap(), ("abc", "λ"));
+    assert!(take_until(one_of(":")).parse("abc").unwrap_err().kind() == ErrorKind::EOF);
+}
@@ -0,0 +1,437 @@
+use crate::prelude::*;
+use crate::str::*;
+
+#[test]
+fn optional() {
+    let sign = one_char('-').opt();
+
+    assert_eq!(sign.parse("-1").unwrap(), (Some("-"), "1"));
+    assert_eq!(sign.parse("1").unwrap(), (None, "1"));
+    assert_eq!(sign.parse("").unwrap(), (None, ""));
+}
+
+#[test]
+fn repeat_exactly() {
+    let hex = take_while(|ch| ch.is_ascii_hexdigit());
+    let pair = Any::new().repeat(2);
+
+    assert_eq!(pair.parse("abc").unwrap(), (vec!["a", "b"], "c"));
+    assert_eq!(Any::new().repeat(0).parse("abc").unwrap(), (vec![], "abc"));
+
+    let err = pair.parse("a").unwrap_err();
+    assert_eq!(err.offset("a"), 1);
+
+    let (res, rest) = hex.then(one_char(';').repeat(3)).parse("ff0;;;x").unwrap();
+    assert_eq!(res, ("ff0", vec![";"; 3]));
+    assert_eq!(rest, "x");
+}
+
+#[test]
+fn take_while_longest_prefix() {
+    let ident = take_while(|ch| ch.is_alphanumeric() || ch == '_');
+
+    assert_eq!(ident.parse("foo_1 = 2").unwrap(), ("foo_1", " = 2"));
+    assert_eq!(ident.parse("größe=1").unwrap(), ("größe", "=1"));
+    assert_eq!(ident.parse("=1").unwrap(), ("", "=1"));
+    assert_eq!(ident.parse("abc").unwrap(), ("abc", ""));
+}
+
+#[test]
+fn take_until_any_parser() {
+    let eol = one_char('\r').or(one_char('\n'));
+    assert_eq!(take_until(eol).parse("héllo\nworld").unwrap(), ("héllo", "\nworld"));
+
+    // stepping lands on char boundaries past multibyte input
+    let arrow = OneOf::<String>::from(&["→", "=>"]);
+    assert_eq!(take_until(arrow).parse("ä ö → ü").unwrap(), ("ä ö ", "→ ü"));
+
+    let err = take_until(Digit::new()).parse("abc").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::EOF);
+    assert_eq!(err.offset("abc"), 3);
+    assert_eq!(err.to_string(), "expected digit, found end of input");
+}
+
+#[test]
+fn take_through_terminator() {
+    let eol = str_seq("\r\n").or(one_char('\n'));
+    let line = take_through(eol);
+
+    assert_eq!(line.parse("a\r\nb\nc").unwrap(), (("a", "\r\n"), "b\nc"));
+    assert_eq!(many0(line).parse("a\r\nb\nc").unwrap(), (vec![("a", "\r\n"), ("b", "\n")], "c"));
+}
+
+#[test]
+fn take_until_partial() {
+    let p = take_until(one_char(';').or(one_char(',')));
+
+    assert!(p.parse(Partial("abc")).unwrap_err().is_incomplete());
+    assert_eq!(p.parse(Partial("ab,c")).unwrap(), ("ab", Partial(",c")));
+    assert!(take_until(str_seq("\r\n")).parse(Partial("ab\r")).unwrap_err().is_incomplete());
+}
+
+#[test]
+fn one_of_longest_match() {
+    let method = OneOf::<String>::from(&["GET", "GETX", "G"]);
+
+    assert_eq!(method.parse("GETX /").unwrap(), ("GETX", " /"));
+    assert_eq!(method.parse("GET /").unwrap(), ("GET", " /"));
+    assert_eq!(method.parse("GE").unwrap(), ("G", "E"));
+    assert_eq!(method.parse("").unwrap_err().needed(), Some(1));
+
+    let err = method.parse(Partial("GET")).unwrap_err();
+    assert!(err.is_incomplete());
+    assert_eq!(method.parse(Partial("GET ")).unwrap(), ("GET", Partial(" ")));
+}
+
+#[test]
+fn one_of_indexed() {
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    enum Kw { Let, Letrec, In }
+    const KEYWORDS: [(&str, Kw); 3] = [("let", Kw::Let), ("letrec", Kw::Letrec), ("in", Kw::In)];
+
+    let names: Vec<&str> = KEYWORDS.iter().map(|(s, _)| *s).collect();
+    let keyword = OneOf::<String>::from(&names).indexed().map(|idx| KEYWORDS[idx].1);
+
+    assert_eq!(keyword.parse("letrec f").unwrap(), (Kw::Letrec, " f"));
+    assert_eq!(keyword.parse("let x").unwrap(), (Kw::Let, " x"));
+    assert_eq!(keyword.parse("in").unwrap(), (Kw::In, ""));
+    assert!(keyword.parse("lex").is_err());
+}
+
+#[test]
+fn separated_lists() {
+    let token = take_while(|ch| ch.is_ascii_alphanumeric());
+    let word = take_while(|ch| ch.is_ascii_alphabetic());
+    let comma = one_char(',').skip_right(many0(whitespace()));
+
+    let encodings = sep_by1(take_while_in(CharClass::alphanumeric()), comma);
+    let (res, rest) = encodings.parse("gzip, deflate, br\r\n").unwrap();
+    assert_eq!(res, vec!["gzip", "deflate", "br"]);
+    assert_eq!(rest, "\r\n");
+    assert!(sep_by1(Letter::new(), one_char(',')).parse("1").is_err());
+
+    let csv = sep_by(many1(Digit::new()).map(|xs| xs.len()), one_char(','));
+    assert_eq!(csv.parse("1,22,3").unwrap(), (vec![1, 2, 1], ""));
+    assert_eq!(csv.parse("").unwrap(), (vec![], ""));
+    // a trailing separator is left for the caller
+    assert_eq!(csv.parse("1,2,").unwrap(), (vec![1, 1], ","));
+
+    let args = sep_end_by(Letter::new(), one_char(','));
+    assert_eq!(args.parse("a,b,)").unwrap(), (vec!["a", "b"], ")"));
+    assert_eq!(args.parse("a,b)").unwrap(), (vec!["a", "b"], ")"));
+    assert_eq!(args.parse(")").unwrap(), (vec![], ")"));
+
+    let stmts = end_by(word, one_char(';'));
+    assert_eq!(stmts.parse("a;b;c").unwrap(), (vec!["a", "b"], "c"));
+
+    // neither side consuming input must not loop forever
+    assert_eq!(sep_by(token, str_seq("")).parse("!").unwrap(), (vec![""], "!"));
+}
+
+#[test]
+fn delimited_and_pairs() {
+    let number = take_while_in(CharClass::digit());
+    let list = delimited(one_char('('), sep_by(number, one_char(',')), one_char(')'));
+
+    assert_eq!(list.parse("(1,2,3) x").unwrap(), (vec!["1", "2", "3"], " x"));
+
+    // the error points at the missing close, not at the open paren
+    let input = "(1,2;";
+    let err = list.parse(input).unwrap_err();
+    assert_eq!(err.offset(input), 4);
+    assert_eq!(err.to_string(), "expected ')', found ';'");
+
+    let header = separated_pair(take_while(|ch| ch != ':'), str_seq(": "), take_until(str_seq("\r\n")));
+    assert_eq!(header.parse("Host: a.b\r\n").unwrap(), (("Host", "a.b"), "\r\n"));
+
+    let arg = preceded(one_char('-'), Letter::new());
+    let stmt = terminated(Letter::new(), one_char(';'));
+    assert_eq!(arg.parse("-v").unwrap(), ("v", ""));
+    assert_eq!(stmt.parse("x;").unwrap(), ("x", ""));
+    assert_eq!(stmt.parse("x").unwrap_err().offset("x"), 1);
+}
+
+#[test]
+fn flat_seq_and_alt() {
+    let version = seq((str_seq("HTTP/"), Digit::new(), one_char('.'), Digit::new()));
+    assert_eq!(version.parse("HTTP/1.1\r\n").unwrap(), (("HTTP/", "1", ".", "1"), "\r\n"));
+
+    let input = "HTTP/1,1";
+    assert_eq!(version.parse(input).unwrap_err().offset(input), 6);
+
+    let bool_lit = alt((str_seq("true"), str_seq("false"), str_seq("null")));
+    assert_eq!(bool_lit.parse("false,").unwrap(), ("false", ","));
+    assert_eq!(bool_lit.parse("nil").unwrap_err().expected().len(), 3);
+
+    let hex = alt((
+        one_char('0'), one_char('1'), one_char('2'), one_char('3'),
+        one_char('4'), one_char('5'), one_char('6'), one_char('7'),
+        one_char('8'), one_char('9'), one_char('a'), one_char('b'),
+        one_char('c'), one_char('d'), one_char('e'), one_char('f'),
+    ));
+    let word = seq((hex, Any::new(), Any::new(), Any::new()));
+    assert_eq!(word.parse("f00d").unwrap(), (("f", "0", "0", "d"), ""));
+    assert!(word.parse("g00d").is_err());
+}
+
+#[test]
+fn bounded_repeat() {
+    // ABNF 1*3DIGIT
+    let digits = many_m_n(Digit::new(), 1..=3);
+    assert_eq!(digits.parse("12345").unwrap(), (vec!["1", "2", "3"], "45"));
+    assert_eq!(digits.parse("7x").unwrap(), (vec!["7"], "x"));
+    assert_eq!(digits.parse("x").unwrap_err().offset("x"), 0);
+
+    let empty = many_m_n(take_while_in(CharClass::alphanumeric()).map(str::len), 2..);
+    assert_eq!(many_m_n(one_char('a'), ..2).parse("aaa").unwrap(), (vec!["a"], "aa"));
+    assert_eq!(many_m_n(one_char('a'), 2..).parse("aaab").unwrap(), (vec!["a"; 3], "b"));
+    assert!(many_m_n(one_char('a'), 2..).parse("ab").is_err());
+    // an item that consumes nothing stops the repetition once `min` is met
+    assert_eq!(empty.parse("!").unwrap(), (vec![0, 0], "!"));
+}
+
+#[test]
+#[should_panic(expected = "repeat range is empty")]
+fn inverted_repeat_range() {
+    #[allow(clippy::reversed_empty_ranges)]
+    many_m_n(one_char('a'), 3..=1);
+}
+
+#[test]
+#[should_panic(expected = "repeat range is empty")]
+fn empty_exclusive_repeat_range() {
+    many_m_n(one_char('a'), ..0);
+}
+
+#[test]
+fn many_till_terminator() {
+    let comment = preceded(str_seq("/*"), many_till(Any::new(), str_seq("*/")));
+    let ((body, end), rest) = comment.parse("/* a*b */ x").unwrap();
+    assert_eq!(body.concat(), " a*b ");
+    assert_eq!(end, "*/");
+    assert_eq!(rest, " x");
+
+    let input = "/* open";
+    let err = comment.parse(input).unwrap_err();
+    assert_eq!(err.offset(input), input.len());
+}
+
+#[test]
+fn fold_and_count() {
+    let digit = Digit::new().map(|d| d.parse::<u32>().unwrap());
+    let number = fold_many1(digit, || 0, |n, d| n * 10 + d);
+
+    assert_eq!(number.parse("1234x").unwrap(), (1234, "x"));
+    assert!(number.parse("x").is_err());
+
+    let sum = fold_many0(terminated(number, one_char('+').opt()), || 0, |acc, n| acc + n);
+    assert_eq!(sum.parse("1+20+300").unwrap(), (321, ""));
+    assert_eq!(sum.parse("").unwrap(), (0, ""));
+
+    assert_eq!(many0_count(one_char('#')).parse("### x").unwrap(), (3, " x"));
+}
+
+#[test]
+fn collect_into_container() {
+    use std::collections::{BTreeSet, HashMap};
+
+    let header = terminated(
+        separated_pair(take_while(|ch| ch != ':'), str_seq(": "), take_until(str_seq("\r\n"))),
+        str_seq("\r\n"),
+    );
+    let headers = Many0::new(header).collect::<HashMap<_, _>>();
+
+    let (map, rest) = headers.parse("Host: a\r\nAccept: */*\r\n\r\n").unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map["Accept"], "*/*");
+    assert_eq!(rest, "\r\n");
+
+    let letters = Many1::new(Letter::new()).collect::<BTreeSet<_>>();
+    let (set, _) = letters.parse("abba").unwrap();
+    assert_eq!(set.into_iter().collect::<String>(), "ab");
+    assert!(letters.parse("1").is_err());
+
+    let word = Many1::new(Letter::new()).collect::<String>();
+    assert_eq!(word.parse("héllo!").unwrap(), ("héllo".to_string(), "!"));
+}
+
+#[test]
+fn many_stops_on_empty_items() {
+    // an item that matches without consuming must not loop forever
+    let maybe_a = || one_char('a').opt();
+
+    assert_eq!(many0(maybe_a()).parse("b").unwrap(), (vec![], "b"));
+    assert_eq!(many0(maybe_a()).parse("aab").unwrap(), (vec![Some("a"), Some("a")], "b"));
+    assert_eq!(many1(maybe_a()).parse("b").unwrap(), (vec![None], "b"));
+    assert_eq!(many1(maybe_a()).parse("ab").unwrap(), (vec![Some("a")], "b"));
+
+    let collected = Many0::new(maybe_a()).collect::<Vec<_>>();
+    assert_eq!(collected.parse("b").unwrap(), many0(maybe_a()).parse("b").unwrap());
+}
+
+#[test]
+fn parse_iter_stops_at_error() {
+    let item = terminated(take_while_in(CharClass::digit()), one_char(';'));
+    let input = "1;22;x;3;";
+
+    let mut iter = parse_iter(&item, input);
+    assert_eq!(iter.next(), Some("1"));
+    assert_eq!(iter.next(), Some("22"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.remaining(), "x;3;");
+
+    let (rest, err) = iter.finish();
+    assert_eq!(err.unwrap().offset(input), 5);
+    assert_eq!(rest, "x;3;");
+
+    // an item that consumes nothing is yielded once
+    let empty = take_while(|ch| ch == 'z');
+    assert_eq!(empty.iter("abc").collect::<Vec<_>>(), vec![""]);
+}
+
+#[test]
+fn lookahead() {
+    let keyword = terminated(str_seq("let"), not(Category::XidContinue));
+    assert_eq!(keyword.parse("let x").unwrap(), ("let", " x"));
+    assert_eq!(keyword.parse("let").unwrap(), ("let", ""));
+    assert_eq!(keyword.parse("letter").unwrap_err().offset("letter"), 3);
+    assert_eq!(keyword.parse("letter").unwrap_err().to_string(), "expected something else, found 't'");
+
+    // skipping into the middle of a char says why it failed
+    let err = one_char('a').skip_n(1).parse("aé").unwrap_err();
+    assert_eq!(err.to_string(), "expected char boundary, found 'é'");
+
+    let sign = peek(one_of("+-"));
+    assert_eq!(sign.parse("-1").unwrap(), ('-', "-1"));
+    assert!(sign.parse("1").is_err());
+
+    let message = terminated(take_until(str_seq("\r\n\r\n")), seq((str_seq("\r\n\r\n"), eof())));
+    assert_eq!(message.parse("GET / HTTP/1.1\r\n\r\n").unwrap(), ("GET / HTTP/1.1", ""));
+
+    let input = "GET / HTTP/1.1\r\n\r\nextra";
+    let err = message.parse(input).unwrap_err();
+    assert_eq!(err.offset(input), 18);
+    assert_eq!(err.to_string(), "expected end of input, found 'e'");
+
+    assert!(eof().parse(Partial("")).unwrap_err().is_incomplete());
+    assert!(not(str_seq("ab")).parse(Partial("a")).unwrap_err().is_incomplete());
+}
+
+#[test]
+fn cut_stops_backtracking() {
+    let number = || take_while_in(CharClass::digit());
+    let post = preceded(str_seq("POST "), cut(terminated(number(), str_seq("\r\n"))));
+    let get = preceded(str_seq("GET "), number());
+    let raw = || take_while(|ch| ch != '\n');
+
+    // without the cut the fallback would accept the whole line
+    let input = "POST 12x";
+    let uncut = preceded(str_seq("POST "), terminated(number(), str_seq("\r\n"))).or(raw());
+    assert_eq!(uncut.parse(input).unwrap(), ("POST 12x", ""));
+
+    let request = post.or(raw());
+    let err = request.parse(input).unwrap_err();
+    assert!(err.is_fatal());
+    assert_eq!(err.offset(input), 7);
+    assert_eq!(err.to_string(), "expected \"\\r\\n\", found 'x'");
+
+    // failing before the cut still tries the other branches
+    let request = alt((preceded(str_seq("POST "), cut(number())), get));
+    assert_eq!(request.parse("GET 1").unwrap(), ("1", ""));
+
+    let items = many0(preceded(one_char('#'), cut(Digit::new())));
+    assert_eq!(items.parse("#1#2 x").unwrap(), (vec!["1", "2"], " x"));
+    let err = items.parse("#1#x").unwrap_err();
+    assert_eq!(err.offset("#1#x"), 3);
+    assert!(preceded(one_char('#'), cut(Digit::new())).opt().parse("#x").is_err());
+}
+
+struct Command<'a> {
+    name: &'static str,
+    args: BoxedParser<'a, &'a str, Vec<&'a str>>,
+}
+
+#[test]
+fn boxed() {
+    let word = || take_while_in(CharClass::alphanumeric());
+    let commands = [
+        Command { name: "get", args: preceded(one_char(' '), word()).repeat(1).boxed() },
+        Command { name: "set", args: many0(preceded(one_char(' '), word())).boxed() },
+    ];
+
+    assert_eq!(commands[0].name, "get");
+    assert_eq!(commands[0].args.parse(" key rest").unwrap(), (vec!["key"], " rest"));
+    assert_eq!(commands[1].args.parse(" key 1").unwrap(), (vec!["key", "1"], ""));
+
+    // boxed parsers still compose, and forward scan to the inner parser
+    let [_, set] = commands;
+    let line = set.args.then(str_seq("\r\n"));
+    assert_eq!(line.parse(" a\r\n").unwrap(), ((vec!["a"], "\r\n"), ""));
+    assert_eq!(str_seq("\r\n").boxed().scan("ab\r\n"), Ok(2));
+}
+
+// built once and kept while the lines it parses come and go
+struct Grammar<'p> {
+    key: BoxedStrParser<'p, String>,
+    count: BoxedStrParser<'p, usize>,
+}
+
+#[test]
+fn boxed_across_inputs() {
+    let sep = String::from("=");
+    let grammar = Grammar {
+        key: BoxedStrParser::new(terminated(take_until(str_seq(&sep)), str_seq(&sep)).map(str::to_string)),
+        count: BoxedStrParser::new(many0_count(one_char('x'))),
+    };
+
+    let mut keys = vec![];
+    for n in 1..4 {
+        let line = format!("k{}={}", n, "x".repeat(n));
+        let (key, rest) = grammar.key.parse(&line).unwrap();
+        assert_eq!(grammar.count.parse(rest).unwrap(), (n, ""));
+        keys.push(key);
+    }
+    assert_eq!(keys, ["k1", "k2", "k3"]);
+
+    let bytes = BoxedSliceParser::new(Map::new(crate::bytes::many1(one_byte(b'a')), |xs: Vec<&[u8]>| xs.len()));
+    for n in 1..3 {
+        let input = vec![b'a'; n];
+        assert_eq!(bytes.parse(&input[..]).unwrap().0, n);
+    }
+}
+
+#[test]
+fn choice_from_vec() {
+    let methods = "GET,PUT,DELETE";
+    let method = choice(
+        methods.split(',')
+            .map(|m| str_seq(m).map(str::len).boxed())
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(method.parse("PUT /").unwrap(), (3, " /"));
+    assert_eq!(method.parse("DELETE").unwrap(), (6, ""));
+
+    let input = "POST /";
+    let err = method.parse(input).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Invalid);
+    assert_eq!(err.to_string(), "expected \"GET\", \"PUT\" or \"DELETE\", found 'P'");
+
+    // trait objects over the fluent trait work too
+    let ps: Vec<Box<dyn ParseStr<'_, Output = &str>>> = vec![Box::new(one_char('a')), Box::new(str_seq("bc"))];
+    assert_eq!(choice(ps).parse("bcd").unwrap(), ("bc", "d"));
+
+    let none: Choice<Seq<u8>> = choice(vec![]);
+    assert!(none.parse("a").is_err());
+}
+
+#[test]
+fn empty_trie() {
+    use crate::matcher::Trie;
+
+    let trie = Trie::default();
+    let err = trie.longest("GET", b"GET", false, || Expected::label("method")).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Invalid);
+    assert!(trie.longest("", b"", true, || Expected::label("method")).is_err());
+}
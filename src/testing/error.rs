@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use crate::error::{ErrorKind, Expected, Position};
+use crate::prelude::*;
+use crate::str::*;
+
+#[test]
+fn expected_one_of() {
+    let method = OneOf::<String>::from(&["GET", "POST"]);
+    let input = "PUT /";
+
+    let err = method.parse(input).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Invalid);
+    assert_eq!(err.offset(input), 0);
+    assert_eq!(err.to_string(), "expected one of \"GET\", \"POST\", found 'P'");
+
+    // failures share the keyword list rather than copying it
+    let other = method.parse("HEAD").unwrap_err();
+    match (&err.expected()[0], &other.expected()[0]) {
+        (Expected::OneOf(a), Expected::OneOf(b)) => assert!(Arc::ptr_eq(a, b)),
+        _ => panic!("expected a OneOf"),
+    }
+}
+
+#[test]
+fn offset_into_original() {
+    let header = take_until(one_char(':'))
+        .skip_right(one_char(':'))
+        .skip_right(whitespace())
+        .then(take_until(str_seq("\r\n")));
+
+    let input = "Host:www";
+    let err = header.parse(input).unwrap_err();
+    assert_eq!(err.offset(input), 5);
+    assert_eq!(err.expected(), &[Expected::label("whitespace")]);
+    assert_eq!(err.to_string(), "expected whitespace, found 'w'");
+
+    let input = "Host: www";
+    let err = header.parse(input).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::EOF);
+    assert_eq!(err.offset(input), input.len());
+    assert_eq!(err.to_string(), "expected \"\\r\\n\", found end of input");
+}
+
+#[test]
+fn or_merges_expected() {
+    let p = one_char(':').or(one_char('=')).or(str_seq("->"));
+
+    let err = p.parse("x").unwrap_err();
+    assert_eq!(err.expected().len(), 3);
+    assert_eq!(err.to_string(), "expected ':', '=' or \"->\", found 'x'");
+
+    // the branch that got further wins
+    let p = str_seq("ab").skip_right(one_char(';'))
+        .or(one_char('a').skip_right(one_char('c')));
+    let err = p.parse("ab,").unwrap_err();
+    assert_eq!(err.offset("ab,"), 2);
+    assert_eq!(err.expected(), &[Expected::literal(";")]);
+}
+
+#[test]
+fn report() {
+    let config = "name: parsing\n\
+        version: 0.1.0\n\
+        \tedition 2021\n";
+
+    let entry = take_while(|ch| ch.is_whitespace())
+        .skip_left(take_until(one_char(':')))
+        .skip_right(one_char(':'))
+        .then(take_until(one_char('\n')))
+        .skip_right(one_char('\n'));
+
+    let (entries, rest) = many0(entry).parse(config).unwrap();
+    assert_eq!(entries.len(), 2);
+
+    let err = take_until(one_char(' ')).skip_right(str_seq(": ")).parse(rest).unwrap_err();
+    assert_eq!(err.position(config), Position { line: 3, column: 9 });
+    assert_eq!(err.report(config).to_string(), "\
+error: expected \": \", found ' '
+ --> 3:9
+  |
+3 | \tedition 2021
+  | \t       ^
+");
+}
+
+#[test]
+fn context_labels() {
+    let method = OneOf::<String>::from(&["GET", "POST"]).context("method");
+
+    let header = take_while(|ch| ch.is_alphanumeric() || ch == '-')
+        .skip_right(one_char(':'))
+        .skip_right(whitespace())
+        .then(take_until(str_seq("\r\n")))
+        .skip_right(str_seq("\r\n"))
+        .context("header");
+
+    let request = method.skip_right(one_char(' '))
+        .then(take_until(str_seq("\r\n")))
+        .skip_right(str_seq("\r\n"))
+        .then(many1(header))
+        .skip_right(str_seq("\r\n"))
+        .context("request");
+
+    let input = "GET / HTTP/1.1\r\nHost www\r\n\r\n";
+    let err = request.parse(input).unwrap_err();
+    assert_eq!(err.context(), &["request", "header"]);
+    assert_eq!(err.position(input), Position { line: 2, column: 5 });
+    assert_eq!(err.to_string(), "in request > in header > expected ':', found ' '");
+
+    let err = request.parse("PUT / HTTP/1.1\r\n").unwrap_err();
+    assert_eq!(err.context(), &["request", "method"]);
+}
+
+#[test]
+fn context_on_both_branches() {
+    let p = str_seq("ab").context("alpha").or(str_seq("cd").context("beta"));
+
+    // neither label covers both alternatives
+    let err = p.parse("x").unwrap_err();
+    assert!(err.context().is_empty());
+    assert_eq!(err.to_string(), "expected \"ab\" or \"cd\", found 'x'");
+
+    let p = str_seq("ab").context("alpha").or(str_seq("cd")).context("pair");
+    let err = p.parse("x").unwrap_err();
+    assert_eq!(err.context(), &["pair"]);
+
+    // the branch that got further keeps its own labels
+    let p = str_seq("ab").skip_right(one_char(';')).context("alpha").or(str_seq("cd").context("beta"));
+    let err = p.parse("ab,").unwrap_err();
+    assert_eq!(err.context(), &["alpha"]);
+}
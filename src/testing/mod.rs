@@ -1,3 +1,15 @@
+mod bytes;
+mod class;
+mod combinator;
+mod error;
+mod nocase;
+mod partial;
+mod precedence;
+mod recursive;
+mod stream;
+mod tokens;
+mod unicode;
+
 use crate::str::*;
 use crate::prelude::*;
 
@@ -34,7 +46,7 @@ fn single_input() {
         Connection: Keep-Alive\r\n\r\n";
 
     let parser = http_parser();
-    let res = parser.parse(&input);
+    let res = parser.parse(input);
     println!("{:?}", res.unwrap());
 }
 
@@ -48,3 +60,21 @@ fn large_input() {
     println!("{:?}", res);
 }
 
+#[test]
+fn large_input_iter() {
+    let input = include_str!("large.txt").to_string();
+    let input = input.replace("\r\n", "\n").replace("\n", "\r\n");
+
+    // header names must not run on into the next request
+    let name = take_while_in(CharClass::new().chars(":\r\n").negate());
+    let line = || terminated(take_until(str_seq("\r\n")), str_seq("\r\n"));
+    let header = separated_pair(name, str_seq(": "), line());
+    let request = terminated(line().then(many1(header)), str_seq("\r\n"));
+
+    let mut iter = request.iter(&input);
+    assert_eq!(iter.by_ref().count(), input.matches("\r\n\r\n").count());
+
+    // the last request has no blank line after it
+    assert!(iter.remaining().starts_with("GET "));
+    assert_eq!(iter.error().unwrap().kind(), ErrorKind::EOF);
+}
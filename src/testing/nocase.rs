@@ -0,0 +1,52 @@
+use crate::prelude::*;
+use crate::str::*;
+
+#[test]
+fn seq_nocase() {
+    let name = str_seq_nocase("Content-Type");
+
+    assert_eq!(name.parse("content-type: x").unwrap(), ("content-type", ": x"));
+    assert_eq!(name.parse("CONTENT-TYPE").unwrap(), ("CONTENT-TYPE", ""));
+    assert!(name.parse("content-length").is_err());
+    assert_eq!(name.parse("conTENT").unwrap_err().needed(), Some(5));
+
+    let street = str_seq_nocase("STRASSE");
+    assert_eq!(street.parse("Straße 1").unwrap(), ("Straße", " 1"));
+    assert_eq!(street.parse("STRAẞE").unwrap(), ("STRAẞE", ""));
+    assert_eq!(str_seq_nocase("straße").parse("STRAẞE").unwrap(), ("STRAẞE", ""));
+    assert!(NoCase::ascii(Seq::<char>::new("STRASSE")).parse("Straße").is_err());
+
+    // final sigma and the Cherokee lowercase letters fold like the others
+    assert_eq!(str_seq_nocase("ΣΟΦΟΣ").parse("σοφο\u{3c2}").unwrap(), ("σοφο\u{3c2}", ""));
+    assert_eq!(str_seq_nocase("\u{13a0}").parse("\u{ab70}").unwrap(), ("\u{ab70}", ""));
+
+    // the Kelvin sign folds to an ASCII 'k'
+    assert_eq!(str_seq_nocase("k").parse("\u{212a}").unwrap(), ("\u{212a}", ""));
+    assert!(str_seq_nocase("s").parse("ß").is_err());
+}
+
+#[test]
+fn one_of_nocase_keywords() {
+    let keyword = one_of_nocase(&["select", "from", "ΣΟΦΊΑ"]);
+
+    assert_eq!(keyword.parse("FROM t").unwrap(), ("FROM", " t"));
+    assert_eq!(keyword.parse("Select *").unwrap(), ("Select", " *"));
+    assert_eq!(keyword.parse("σοφία").unwrap(), ("σοφία", ""));
+
+    let err = keyword.parse("where").unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Invalid);
+    assert_eq!(keyword.parse("SEL").unwrap_err().needed(), Some(3));
+}
+
+#[test]
+fn bytes_nocase() {
+    use crate::bytes::{byte_seq_nocase, one_of_nocase};
+
+    let input: &[u8] = b"HOST: a";
+    assert_eq!(byte_seq_nocase(b"host").parse(input).unwrap(), (&b"HOST"[..], &b": a"[..]));
+    assert_eq!(one_of_nocase(&["get", "host"]).parse(input).unwrap().0, b"HOST");
+
+    let err = byte_seq_nocase(b"host").parse(Partial(&b"Ho"[..])).unwrap_err();
+    assert!(err.is_incomplete());
+    assert_eq!(err.needed(), Some(2));
+}
@@ -0,0 +1,56 @@
+use crate::bytes::*;
+use crate::prelude::*;
+
+const REQUEST: &[u8] = b"GET /index.html HTTP/1.1\r\n\
+    Host: example.com\r\n\
+    Connection: Keep-Alive\r\n\r\n";
+
+#[test]
+fn incomplete_until_whole_message() {
+    let method = OneOf::<String>::from(&["GET", "POST"]);
+
+    let req = method.skip_right(one_byte(b' '))
+        .then(take_until(byte_seq(b"\r\n")))
+        .skip_right(byte_seq(b"\r\n"));
+
+    let header = take_while(|b| b.is_ascii_alphanumeric() || *b == b'-')
+        .skip_right(byte_seq(b": "))
+        .then(take_until(byte_seq(b"\r\n")))
+        .skip_right(byte_seq(b"\r\n"));
+
+    let parser = req.then(many1(header)).skip_right(byte_seq(b"\r\n"));
+
+    for n in 0..REQUEST.len() {
+        let err = parser.parse(Partial(&REQUEST[..n])).unwrap_err();
+        assert!(err.is_incomplete(), "{} bytes: {:?}", n, err);
+    }
+
+    let ((_, headers), rest) = parser.parse(Partial(REQUEST)).unwrap();
+    assert_eq!(headers.len(), 2);
+    assert!(rest.0.is_empty());
+
+    // complete input reports the same truncation as a hard failure
+    let err = parser.parse(&REQUEST[..REQUEST.len() - 2]).unwrap_err();
+    assert!(!err.is_incomplete());
+
+    let err = parser.parse(Partial(&b"PUT / HTTP/1.1\r\n"[..])).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Invalid);
+}
+
+#[test]
+fn needed() {
+    let err = byte_seq(b"\r\n\r\n").parse(Partial(&b"\r\n"[..])).unwrap_err();
+    assert_eq!(err.needed(), Some(2));
+
+    let err = OneOf::<String>::from(&["GET", "DELETE"]).parse(Partial(&b"DE"[..])).unwrap_err();
+    assert_eq!(err.needed(), Some(4));
+
+    // a run that reaches the end of the buffer might continue
+    let err = take_while(u8::is_ascii_digit).parse(Partial(&b"123"[..])).unwrap_err();
+    assert!(err.is_incomplete());
+    assert!(take_while(u8::is_ascii_digit).parse(Partial(&b"123;"[..])).is_ok());
+
+    let err = many0(Digit::new()).parse(Partial(&b"12"[..])).unwrap_err();
+    assert!(err.is_incomplete());
+    assert_eq!(many0(Digit::new()).parse(&b"12"[..]).unwrap().0.len(), 2);
+}
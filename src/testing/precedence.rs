@@ -0,0 +1,116 @@
+use crate::prelude::*;
+use crate::str::*;
+
+fn number<'a>() -> impl ParseStr<'a, Output = i64> {
+    preceded(peek(CharClass::digit()), take_while_in(CharClass::digit()))
+        .map(|s: &str| s.parse().unwrap_or(0))
+}
+
+#[test]
+fn chains() {
+    let sub = || one_char('-').map(|_| |a: i64, b: i64| a - b);
+    let pow = one_char('^').map(|_| |a: i64, b: i64| a.pow(b as u32));
+
+    assert_eq!(chainl1(number(), sub()).parse("10-3-2").unwrap(), (5, ""));
+    assert_eq!(chainr1(number(), sub()).parse("10-3-2").unwrap(), (9, ""));
+    assert_eq!(chainr1(number(), pow).parse("2^3^2").unwrap(), (512, ""));
+
+    // a trailing operator without an operand is left unconsumed
+    assert_eq!(chainl1(number(), sub()).parse("7-x").unwrap(), (7, "-x"));
+    assert!(chainl1(number(), sub()).parse("x").is_err());
+}
+
+#[derive(Debug, PartialEq)]
+enum Expr {
+    Num(i64),
+    Neg(Box<Expr>),
+    Fact(Box<Expr>),
+    Bin(char, Box<Expr>, Box<Expr>),
+    Call(Box<Expr>, Vec<Expr>),
+}
+
+fn bin(a: Expr, op: char, b: Expr) -> Expr {
+    Expr::Bin(op, Box::new(a), Box::new(b))
+}
+
+fn eval(e: &Expr) -> i64 {
+    match e {
+        Expr::Num(n) => *n,
+        Expr::Neg(x) => -eval(x),
+        Expr::Fact(x) => (1..=eval(x)).product(),
+        Expr::Bin(op, a, b) => {
+            let (a, b) = (eval(a), eval(b));
+            match op {
+                '+' => a + b,
+                '-' => a - b,
+                '*' => a * b,
+                '/' => a / b,
+                _ => a.pow(b as u32),
+            }
+        },
+        Expr::Call(f, args) => eval(f) + args.iter().map(eval).sum::<i64>(),
+    }
+}
+
+fn expr<'a>() -> Recursive<'a, &'a str, Expr> {
+    recursive(|expr| {
+        let atom = alt((
+            delimited(one_char('('), expr, one_char(')')),
+            number().map(Expr::Num),
+        ));
+
+        // a call's arguments are the postfix operator's output
+        let args = delimited(one_char('('), sep_by(number().map(Expr::Num), one_char(',')), one_char(')'));
+
+        precedence(atom)
+            .prefix(one_char('-'), 3, |_, x| Expr::Neg(Box::new(x)))
+            .postfix(one_char('!'), 5, |x, _| Expr::Fact(Box::new(x)))
+            .postfix(args, 6, |f, args| Expr::Call(Box::new(f), args))
+            .infix(one_of("+-"), 1, Assoc::Left, bin)
+            .infix(one_of("*/"), 2, Assoc::Left, bin)
+            .infix(one_of("^"), 4, Assoc::Right, bin)
+    })
+}
+
+#[test]
+fn pratt() {
+    let expr = expr();
+    let eval_str = |s| eval(&expr.parse(s).unwrap().0);
+
+    assert_eq!(eval_str("1+2*3"), 7);
+    assert_eq!(eval_str("(1+2)*3"), 9);
+    assert_eq!(eval_str("10-4-3"), 3);
+    assert_eq!(eval_str("2^3^2"), 512);
+    assert_eq!(eval_str("-2^2"), -4);
+    assert_eq!(eval_str("-3*2"), -6);
+    assert_eq!(eval_str("3!*2"), 12);
+    assert_eq!(eval_str("2^3!"), 64);
+
+    assert_eq!(
+        expr.parse("-1!").unwrap().0,
+        Expr::Neg(Box::new(Expr::Fact(Box::new(Expr::Num(1))))),
+    );
+    assert_eq!(expr.parse("1+2 rest").unwrap().1, " rest");
+
+    let call = Expr::Call(Box::new(Expr::Num(1)), vec![Expr::Num(2), Expr::Num(3)]);
+    assert_eq!(expr.parse("1(2,3)").unwrap().0, call);
+    assert_eq!(eval_str("2*1(2,3)"), 12);
+}
+
+#[test]
+fn pratt_errors() {
+    let expr = expr();
+
+    let input = "1+";
+    let err = expr.parse(input).unwrap_err();
+    assert_eq!(err.offset(input), 2);
+
+    let err = expr.parse("x").unwrap_err();
+    assert_eq!(err.to_string(), "expected '-', '(' or [0-9], found 'x'");
+}
+
+#[test]
+#[should_panic(expected = "operator precedence 4294967295 is too high")]
+fn precedence_overflow() {
+    precedence(number()).infix(one_char('+'), u32::MAX, Assoc::Left, |a, _, b| a + b);
+}
@@ -0,0 +1,44 @@
+use crate::prelude::*;
+use crate::str::*;
+
+// the depth of the deepest list
+fn nesting<'a>() -> Recursive<'a, &'a str, usize> {
+    recursive(|list| {
+        // once a list is open a missing bracket is reported where it is missing
+        preceded(one_char('['), cut(terminated(sep_by(list, one_char(',')), one_char(']'))))
+            .map(|xs: Vec<usize>| xs.into_iter().max().unwrap_or(0) + 1)
+    })
+}
+
+#[test]
+fn nested_lists() {
+    let list = nesting();
+
+    assert_eq!(list.parse("[]").unwrap(), (1, ""));
+    assert_eq!(list.parse("[[],[[]],[]] x").unwrap(), (3, " x"));
+
+    let input = "[[],[[]";
+    assert_eq!(list.parse(input).unwrap_err().offset(input), input.len());
+}
+
+#[test]
+fn deep_nesting() {
+    let depth = 10_000;
+    let input = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+    assert_eq!(nesting().parse(&input).unwrap(), (depth, ""));
+
+    let unclosed = &input[..input.len() - 1];
+    assert_eq!(nesting().parse(unclosed).unwrap_err().offset(unclosed), unclosed.len());
+}
+
+#[test]
+fn parenthesized_expr() {
+    let expr = recursive(|expr| {
+        let number = take_while_in(CharClass::digit()).map(|s| s.parse::<i64>().unwrap_or(0));
+        let atom = delimited(one_char('('), expr, one_char(')')).or(number);
+        sep_by1(atom, one_char('+')).map(|xs| xs.iter().sum::<i64>())
+    });
+
+    assert_eq!(expr.parse("1+(2+(3+4))+5").unwrap(), (15, ""));
+}
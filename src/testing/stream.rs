@@ -0,0 +1,104 @@
+use std::io::{self, BufReader, Read};
+
+use crate::byte_parser::stream::{Reader, Status, StreamError};
+use crate::bytes::*;
+use crate::prelude::*;
+
+type Request<'a> = (&'a [u8], Vec<&'a [u8]>);
+
+// generic over the input lifetime so one parser serves every call
+fn request() -> impl for<'a> Parse<&'a [u8], Output = Request<'a>>
+    + for<'a> Parse<Partial<&'a [u8]>, Output = Request<'a>>
+{
+    let line = take_until(byte_seq(b"\r\n")).skip_right(byte_seq(b"\r\n"));
+    let header = take_while(|b| b.is_ascii_alphanumeric() || *b == b'-')
+        .skip_right(byte_seq(b": "))
+        .skip_right(take_until(byte_seq(b"\r\n")))
+        .skip_right(byte_seq(b"\r\n"));
+
+    line.then(many0(header)).skip_right(byte_seq(b"\r\n"))
+}
+
+fn collect(input: &[u8], capacity: usize) -> Result<Vec<(String, usize)>, String> {
+    let mut reader = Reader::new(BufReader::with_capacity(capacity, input));
+    let parser = request();
+    let mut acc = Vec::new();
+
+    loop {
+        match reader.parse(&parser) {
+            Ok(Status::Parsed((line, headers))) => {
+                acc.push((String::from_utf8_lossy(line).to_string(), headers.len()));
+            },
+            Ok(Status::Incomplete) => continue,
+            Ok(Status::Done) => return Ok(acc),
+            Err(StreamError::Parse(e)) => return Err(e.to_string()),
+            Err(StreamError::Io(e)) => return Err(e.to_string()),
+        }
+    }
+}
+
+#[test]
+fn keep_alive_requests() {
+    let input = b"GET / HTTP/1.1\r\nHost: a\r\n\r\n\
+        GET /b HTTP/1.1\r\nHost: b\r\nAccept: */*\r\n\r\n\
+        POST /c HTTP/1.1\r\n\r\n";
+
+    for capacity in [1, 3, 7, 64] {
+        let res = collect(input, capacity).unwrap();
+        assert_eq!(res, vec![
+            ("GET / HTTP/1.1".to_string(), 1),
+            ("GET /b HTTP/1.1".to_string(), 2),
+            ("POST /c HTTP/1.1".to_string(), 0),
+        ]);
+    }
+}
+
+#[test]
+fn truncated_stream() {
+    let input = b"GET / HTTP/1.1\r\nHost: a\r\n\r\nGET /b HTTP/1.1\r\nHo";
+
+    let err = collect(input, 4).unwrap_err();
+    assert!(err.starts_with("expected \"\\r\\n\""), "{}", err);
+}
+
+// hands out a few bytes per read, interrupted every other call
+struct Trickle<'a> {
+    data: &'a [u8],
+    interrupt: bool,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+
+        let n = buf.len().min(self.data.len()).min(3);
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn message_spanning_fills() {
+    let body = b"0123456789abcdefghijklmnopqrstuvwxyz0123456789";
+    let trickle = Trickle { data: body, interrupt: false };
+    let mut reader = Reader::new(BufReader::with_capacity(4, trickle));
+    let parser = byte_seq(body);
+
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match reader.parse(&parser) {
+            Ok(Status::Parsed(res)) => assert_eq!(res, &body[..]),
+            Ok(Status::Incomplete) => continue,
+            Ok(Status::Done) => break,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    // the first attempt sees a few bytes and asks for the rest in one go
+    assert_eq!(attempts, 3);
+}
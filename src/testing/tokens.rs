@@ -0,0 +1,60 @@
+use crate::matcher::Token;
+use crate::tokens::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Tok<'a> {
+    Ident(&'a str),
+    Eq,
+    Num(i64),
+    Semi,
+}
+
+impl Token for Tok<'_> {
+}
+
+#[test]
+fn token_slices() {
+    use Tok::*;
+
+    let input = [Ident("x"), Eq, Num(1), Semi, Ident("y"), Eq, Num(2), Semi];
+
+    let ident = take_while(|t| matches!(t, Ident(_)));
+    let value = take_until(token_seq(&[Semi]));
+    let stmt = ident.skip_right(token(Eq)).then(value).skip_right(token(Semi));
+
+    let (stmts, rest) = many1(stmt).parse(&input[..]).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(stmts[1], (&[Ident("y")][..], &[Num(2)][..]));
+    assert!(one_of(&[Eq, Semi]).parse(&input[..]).is_err());
+
+    let ident = take_while(|t| matches!(t, Ident(_)));
+    let value = terminated(take_until(token(Semi)), cut(token(Semi)));
+    let assign = preceded(not(token(Semi)), separated_pair(ident, token(Eq), value));
+    let (pairs, rest) = terminated(many1(assign), eof()).parse(&input[..]).unwrap();
+    assert!(rest.is_empty());
+    assert_eq!(pairs[0], (&[Ident("x")][..], &[Num(1)][..]));
+    assert!(delimited(token(Eq), token(Num(1)), token(Semi)).parse(&input[1..]).is_ok());
+    assert!(peek(token(Eq)).parse(&input[1..]).is_ok_and(|(_, xs)| xs.len() == 7));
+}
+
+fn count<I, P>(p: P, input: I) -> usize
+where
+    P: Parse<I>,
+{
+    let mut n = 0;
+    let mut cursor = input;
+    while let Ok((_, xs)) = p.parse(cursor) {
+        n += 1;
+        cursor = xs;
+    }
+    n
+}
+
+#[test]
+fn generic_over_input() {
+    use crate::matcher::Any;
+
+    assert_eq!(count(Any::new(), "añb"), 3);
+    assert_eq!(count(Any::new(), "añb".as_bytes()), 4);
+    assert_eq!(count(Any::new(), &[1, 2][..]), 2);
+}
@@ -0,0 +1,55 @@
+use crate::prelude::*;
+use crate::str::*;
+
+#[test]
+fn single_char_matchers() {
+    assert_eq!(Any::new().parse("éa").unwrap(), ("é", "a"));
+    assert_eq!(Letter::new().parse("ñu").unwrap(), ("ñ", "u"));
+    assert_eq!(Letter::new().parse("日本").unwrap(), ("日", "本"));
+    assert_eq!(Digit::new().parse("٣4").unwrap(), ("٣", "4"));
+    assert_eq!(Whitespace::new().parse("\u{3000}x").unwrap(), ("\u{3000}", "x"));
+
+    assert!(Letter::new().parse("1a").is_err());
+    assert!(Digit::new().parse("½").is_err());
+    assert_eq!(Digit::new().parse("").unwrap_err().needed(), Some(1));
+}
+
+#[test]
+fn categories() {
+    let ident = Category::XidStart.then(many0(Category::XidContinue));
+
+    let ((first, rest), remaining) = ident.parse("δx_9 = 1").unwrap();
+    assert_eq!(first, 'δ');
+    assert_eq!(rest, vec!['x', '_', '9']);
+    assert_eq!(remaining, " = 1");
+    assert!(ident.parse("9x").is_err());
+
+    assert_eq!(Category::Punctuation.parse("¿qué?").unwrap(), ('¿', "qué?"));
+    assert_eq!(Category::Alphanumeric.parse("٣").unwrap(), ('٣', ""));
+
+    let err = Category::Alphabetic.parse("1").unwrap_err();
+    assert_eq!(err.to_string(), "expected letter, found '1'");
+}
+
+#[test]
+fn satisfy_returns_char() {
+    let upper = satisfy(char::is_uppercase);
+
+    assert_eq!(upper.parse("Äb").unwrap(), ('Ä', "b"));
+    assert_eq!(upper.parse("äb").unwrap_err().to_string(), "expected matching character, found 'ä'");
+    assert_eq!(upper.parse("").unwrap_err().needed(), Some(1));
+    assert_eq!(upper.parse("").unwrap_err().to_string(), "expected matching character, found end of input");
+
+    let upper = satisfy(char::is_uppercase).label("uppercase letter");
+    assert_eq!(upper.parse("ä").unwrap_err().to_string(), "expected uppercase letter, found 'ä'");
+}
+
+#[test]
+fn byte_seq_inside_char() {
+    // a needle that ends inside a char is a mismatch, not a panic
+    let lead = Seq::<u8>::new(b"\xc3");
+    assert_eq!(lead.parse("é").unwrap_err().kind(), ErrorKind::Invalid);
+    assert_eq!(take_until(Seq::<u8>::new(b"\xc3")).parse("café").unwrap_err().kind(), ErrorKind::EOF);
+
+    assert_eq!(Seq::<u8>::new("é".as_bytes()).parse("éa").unwrap(), ("é", "a"));
+}
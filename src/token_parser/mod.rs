@@ -0,0 +1,94 @@
+use crate::combinator::{And, BoxedParser, Context, ManyN, Map, Optional, Or};
+use crate::combinator::{Skip, Left, Right, SkipN};
+use crate::parser::{Parse, ParseIter};
+
+pub trait ParseTokens<'a, T: 'a>: Parse<&'a [T]> {
+    fn then<P>(self, other: P) -> And<Self, P>
+    where
+        P: ParseTokens<'a, T>,
+        Self: Sized,
+    {
+        And::new(self, other)
+    }
+
+    fn map<F, B>(self, f: F) -> Map<Self, F>
+    where
+        F: Fn(Self::Output) -> B,
+        Self: Sized,
+    {
+        Map::new(self, f)
+    }
+
+    fn or<P>(self, other: P) -> Or<Self, P>
+    where
+        P: ParseTokens<'a, T>,
+        Self: Sized,
+    {
+        Or::new(self, other)
+    }
+
+    fn skip_left<P>(self, other: P) -> Skip<Left, Self, P>
+    where
+        P: ParseTokens<'a, T>,
+        Self: Sized,
+    {
+        Skip::<Left, _, _>::new(self, other)
+    }
+
+    fn skip_right<P>(self, other: P) -> Skip<Right, Self, P>
+    where
+        P: ParseTokens<'a, T>,
+        Self: Sized,
+    {
+        Skip::<Right, _, _>::new(self, other)
+    }
+
+    fn skip_n(self, n: usize) -> SkipN<Self>
+    where
+        Self: Sized,
+    {
+        SkipN::new(self, n)
+    }
+
+    fn opt(self) -> Optional<Self>
+    where
+        Self: Sized,
+    {
+        Optional::new(self)
+    }
+
+    fn repeat(self, n: usize) -> ManyN<Self>
+    where
+        Self: Sized,
+    {
+        ManyN::new(self, n)
+    }
+
+    fn iter(&self, input: &'a [T]) -> ParseIter<'_, Self, &'a [T]>
+    where
+        Self: Sized,
+    {
+        ParseIter::new(self, input)
+    }
+
+    fn context(self, label: &'static str) -> Context<Self>
+    where
+        Self: Sized,
+    {
+        Context::new(self, label)
+    }
+
+    fn boxed<'p>(self) -> BoxedParser<'p, &'a [T], Self::Output>
+    where
+        Self: Sized + 'p,
+    {
+        BoxedParser::new(self)
+    }
+}
+
+impl<'a, T, P> ParseTokens<'a, T> for P
+where
+    P: Parse<&'a [T]>,
+    T: 'a,
+{
+}